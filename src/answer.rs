@@ -0,0 +1,109 @@
+use std::fmt;
+
+/// The answer to a part of a puzzle
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// A number, such as a count or a sum
+    Integer(i64),
+    /// A sequence of letters, or the name of a node
+    Text(String),
+    /// A digest, displayed in lowercase hexadecimal
+    Digest(Vec<u8>),
+}
+
+impl Answer {
+    /// The value of the answer, if it is a number
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::Answer;
+    /// assert_eq!(Some(42), Answer::from(42usize).as_integer());
+    /// assert_eq!(None, Answer::from("tknk").as_integer());
+    /// ```
+    pub fn as_integer(&self) -> Option<i64> {
+        match *self {
+            Answer::Integer(n) => Some(n),
+            _ => None,
+        }
+    }
+
+    /// Whether the recorded text stands for this answer,
+    /// even if it is not formatted exactly as the answer is displayed:
+    /// surrounding spaces are ignored, numbers are compared by value
+    /// and letters regardless of their case.
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::Answer;
+    /// assert!(Answer::Integer(38415).matches("038415 "));
+    /// assert!(!Answer::Integer(38415).matches("38416"));
+    /// assert!(Answer::from("QPRYCIOLU").matches("qpryciolu"));
+    /// assert!(Answer::Digest(vec![0x9d, 0xe8]).matches("9DE8"));
+    /// ```
+    pub fn matches(&self, recorded: &str) -> bool {
+        let recorded = recorded.trim();
+        match *self {
+            Answer::Integer(n) => recorded.parse() == Ok(n),
+            _ => recorded.eq_ignore_ascii_case(&self.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::Text(ref s) => write!(f, "{}", s),
+            Answer::Digest(ref bytes) => bytes.iter().try_for_each(|b| write!(f, "{:02x}", b)),
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Integer(n as i64)
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(i32, i64, isize, u8, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// Compares the answer as it is displayed
+///
+/// # Examples
+/// ```
+/// use advent_of_code::Answer;
+/// assert_eq!("1031", Answer::Integer(1031));
+/// assert_eq!(Answer::Digest(vec![0xa2, 0x58]), "a258");
+/// ```
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        match *self {
+            Answer::Text(ref s) => s == other,
+            _ => self.to_string().as_str() == *other,
+        }
+    }
+}
+
+impl PartialEq<Answer> for &str {
+    fn eq(&self, other: &Answer) -> bool {
+        other == self
+    }
+}
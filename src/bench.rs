@@ -0,0 +1,130 @@
+use std::fs::OpenOptions;
+use std::io;
+use std::io::Write;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use {Answer, SolveError, Solver};
+
+/// Statistics over the durations of repeated runs
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+/// Converts a duration into a number of nanoseconds
+fn nanos(d: Duration) -> f64 {
+    d.as_secs() as f64 * 1e9 + f64::from(d.subsec_nanos())
+}
+
+/// Converts a number of nanoseconds into a duration
+fn from_nanos(n: f64) -> Duration {
+    let n = n.round() as u64;
+    Duration::new(n / 1_000_000_000, (n % 1_000_000_000) as u32)
+}
+
+impl Stats {
+    /// Computes the statistics of the provided samples,
+    /// or None if there is no sample.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use advent_of_code::bench::Stats;
+    /// let samples: Vec<Duration> = [4, 2, 9, 5].iter().map(|&ms| Duration::from_millis(ms)).collect();
+    /// let stats = Stats::from_samples(&samples).unwrap();
+    /// assert_eq!(4, stats.runs);
+    /// assert_eq!(Duration::from_millis(2), stats.min);
+    /// assert_eq!(Duration::new(0, 4_500_000), stats.median);
+    /// assert_eq!(Duration::from_millis(5), stats.mean);
+    /// assert_eq!(Duration::new(0, 2_943_920), stats.stddev);
+    /// assert!(Stats::from_samples(&[]).is_none());
+    /// ```
+    pub fn from_samples(samples: &[Duration]) -> Option<Stats> {
+        let runs = samples.len();
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let min = *sorted.first()?;
+        let median = if runs % 2 == 1 {
+            sorted[runs / 2]
+        } else {
+            from_nanos((nanos(sorted[runs / 2 - 1]) + nanos(sorted[runs / 2])) / 2.)
+        };
+
+        let mean = sorted.iter().map(|&d| nanos(d)).sum::<f64>() / runs as f64;
+        let variance = if runs > 1 {
+            sorted.iter().map(|&d| (nanos(d) - mean).powi(2)).sum::<f64>() / (runs - 1) as f64
+        } else {
+            0.
+        };
+
+        Some(Stats {
+            runs,
+            min,
+            median,
+            mean: from_nanos(mean),
+            stddev: from_nanos(variance.sqrt()),
+        })
+    }
+}
+
+/// Runs the solver on the input `warmup` times without measuring it,
+/// then `runs` times while timing each run.
+/// At least one timed run is always performed.
+///
+/// Returns the last answer with the statistics of the timed runs,
+/// or the first error returned by the solver.
+pub fn bench(
+    solver: Solver,
+    input: &str,
+    warmup: usize,
+    runs: usize,
+) -> Result<(Answer, Stats), SolveError> {
+    for _ in 0..warmup {
+        solver(input)?;
+    }
+
+    let runs = runs.max(1);
+    let mut answer = None;
+    let mut samples = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        answer = Some(solver(input)?);
+        samples.push(start.elapsed());
+    }
+
+    let answer = answer.expect("At least one run has been timed");
+    let stats = Stats::from_samples(&samples).expect("At least one run has been timed");
+    Ok((answer, stats))
+}
+
+/// Header of the CSV file storing the benchmark results
+pub const CSV_HEADER: &str = "timestamp,year,day,part,runs,min_ns,median_ns,mean_ns,stddev_ns";
+
+/// Appends the statistics of a benchmark to a CSV file,
+/// writing the header first if the file is new or empty.
+pub fn append_csv(filename: &str, year: u32, day: u32, part: u32, stats: &Stats) -> io::Result<()> {
+    let mut f = OpenOptions::new().create(true).append(true).open(filename)?;
+    if f.metadata()?.len() == 0 {
+        writeln!(f, "{}", CSV_HEADER)?;
+    }
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    writeln!(
+        f,
+        "{},{},{},{},{},{},{},{},{}",
+        timestamp,
+        year,
+        day,
+        part,
+        stats.runs,
+        nanos(stats.min),
+        nanos(stats.median),
+        nanos(stats.mean),
+        nanos(stats.stddev)
+    )
+}
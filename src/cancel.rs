@@ -0,0 +1,75 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use SolveError;
+
+/// Token shared between the runner and a solver,
+/// allowing the runner to ask the solver to stop
+#[derive(Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    /// Creates a token which has not been cancelled yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Asks the solvers checking the token to stop
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether the token has been cancelled
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::cancel::CancelToken;
+    /// let token = CancelToken::new();
+    /// let shared = token.clone();
+    /// assert!(!shared.is_cancelled());
+    /// token.cancel();
+    /// assert!(shared.is_cancelled());
+    /// ```
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+thread_local! {
+    /// Token checked by the solver running on the thread, if any
+    static CURRENT: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+/// Sets the token checked by the solvers running on the current thread
+pub fn set_current(token: Option<CancelToken>) {
+    CURRENT.with(|current| *current.borrow_mut() = token);
+}
+
+/// Checks whether the solver running on the current thread has been asked to stop.
+/// Long loops call it regularly, and return the error as soon as it is cancelled.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::cancel::{self, CancelToken};
+/// assert!(cancel::check().is_ok());
+/// let token = CancelToken::new();
+/// cancel::set_current(Some(token.clone()));
+/// assert!(cancel::check().is_ok());
+/// token.cancel();
+/// assert!(cancel::check().is_err());
+/// ```
+pub fn check() -> Result<(), SolveError> {
+    let cancelled = CURRENT.with(|current| {
+        current
+            .borrow()
+            .as_ref()
+            .is_some_and(|token| token.is_cancelled())
+    });
+    if cancelled {
+        Err(SolveError::Cancelled)
+    } else {
+        Ok(())
+    }
+}
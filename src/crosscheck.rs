@@ -0,0 +1,191 @@
+use std::fmt;
+use generate::{Generator, Rng};
+use param::{self, Values};
+use {Answer, Puzzle, Solver};
+
+/// Slow but straightforward implementation of a part,
+/// whose answers must always be the same as the ones of the optimised solver
+pub struct Check {
+    /// Part solved by both implementations, numbered from 1
+    pub part: u32,
+    /// Reference implementation, such as a complete simulation
+    pub reference: Solver,
+    /// Generates inputs small enough for the reference to be solved quickly
+    pub generate: Generator,
+    /// Values of the parameters scaled down for the reference, by name
+    pub params: &'static [(&'static str, u64)],
+    /// Smaller variants of an input, tried in order to minimize a failing one
+    pub shrink: fn(&str) -> Vec<String>,
+}
+
+/// Input on which the two implementations of a part disagree
+pub struct Mismatch {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    /// Seed of the generated input, if the input was generated
+    pub seed: Option<u64>,
+    /// Smallest failing input found from the original one
+    pub input: String,
+    /// Outcome of the reference implementation on the minimized input
+    pub expected: Result<Answer, String>,
+    /// Outcome of the optimised solver on the minimized input
+    pub actual: Result<Answer, String>,
+}
+
+/// Outcome of a solver, keeping the error as its message
+/// so that both outcomes can be compared
+fn outcome(solver: Solver, input: &str) -> Result<Answer, String> {
+    solver(input).map_err(|e| e.to_string())
+}
+
+/// Shows an outcome the way the command line prints it
+fn format_outcome(outcome: &Result<Answer, String>) -> String {
+    match *outcome {
+        Ok(ref answer) => answer.to_string(),
+        Err(ref e) => format!("error: {}", e),
+    }
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} day {} part {}", self.year, self.day, self.part)?;
+        if let Some(seed) = self.seed {
+            write!(f, " with seed {}", seed)?;
+        }
+        write!(
+            f,
+            ": expected {}, got {} on\n{}",
+            format_outcome(&self.expected),
+            format_outcome(&self.actual),
+            self.input
+        )
+    }
+}
+
+/// Removes chunks of items separated by `separator`,
+/// from halves of the input down to single items
+///
+/// # Examples
+/// ```
+/// use advent_of_code::crosscheck::shrink_items;
+/// assert_eq!(vec!["c,d", "a,b", "b,c,d", "a,c,d", "a,b,d", "a,b,c"], shrink_items("a,b,c,d", ","));
+/// assert!(shrink_items("a", ",").is_empty());
+/// ```
+pub fn shrink_items(s: &str, separator: &str) -> Vec<String> {
+    let items: Vec<&str> = s.split(separator).collect();
+    let mut candidates = Vec::new();
+    let mut chunk = items.len() / 2;
+    while chunk > 0 {
+        for start in (0..items.len()).step_by(chunk) {
+            let kept: Vec<&str> = items[..start]
+                .iter()
+                .chain(items.iter().skip(start + chunk))
+                .cloned()
+                .collect();
+            candidates.push(kept.join(separator));
+        }
+        chunk /= 2;
+    }
+    candidates
+}
+
+/// Replaces an input made of a single number with smaller numbers
+///
+/// # Examples
+/// ```
+/// use advent_of_code::crosscheck::shrink_number;
+/// assert_eq!(vec!["5", "9"], shrink_number("10"));
+/// assert!(shrink_number("0").is_empty());
+/// ```
+pub fn shrink_number(s: &str) -> Vec<String> {
+    match s.trim().parse::<u64>() {
+        Ok(n) if n > 1 => vec![(n / 2).to_string(), (n - 1).to_string()],
+        Ok(1) => vec![String::from("0")],
+        _ => vec![],
+    }
+}
+
+/// Shrinks a failing input for as long as one of its smaller variants still fails
+///
+/// # Examples
+/// ```
+/// use advent_of_code::crosscheck::{minimize, shrink_items};
+/// let fails = |s: &str| s.contains('x') && s.contains('z');
+/// let shrink = |s: &str| shrink_items(s, ",");
+/// assert_eq!("x,z", minimize("a,x,b,c,z,d", shrink, fails));
+/// ```
+pub fn minimize<S, F>(input: &str, shrink: S, fails: F) -> String
+where
+    S: Fn(&str) -> Vec<String>,
+    F: Fn(&str) -> bool,
+{
+    let mut input = input.to_string();
+    while let Some(smaller) = shrink(&input).into_iter().find(|s| fails(s)) {
+        input = smaller;
+    }
+    input
+}
+
+/// Runs both implementations of a part on the input,
+/// with the scaled-down parameters of the check.
+/// Returns a minimized mismatch if they disagree.
+fn compare(puzzle: &Puzzle, check: &Check, input: &str, seed: Option<u64>) -> Option<Mismatch> {
+    let solver = puzzle.solver(check.part)?;
+    let disagree = |input: &str| outcome(check.reference, input) != outcome(solver, input);
+    if !disagree(input) {
+        return None;
+    }
+    let input = minimize(input, check.shrink, disagree);
+    Some(Mismatch {
+        year: puzzle.year,
+        day: puzzle.day,
+        part: check.part,
+        seed,
+        expected: outcome(check.reference, &input),
+        actual: outcome(solver, &input),
+        input,
+    })
+}
+
+/// Values of the scaled-down parameters of the check
+fn params(check: &Check) -> Values {
+    check
+        .params
+        .iter()
+        .map(|&(name, value)| (name.to_string(), value))
+        .collect()
+}
+
+/// Cross-checks the parts of the puzzle having a reference implementation
+/// on the inputs generated from the seeds, or on the provided input.
+/// Returns every mismatch found, one at most per check.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::crosscheck::cross_check;
+/// use advent_of_code::get_puzzle;
+/// let puzzle = get_puzzle(2017, 17).unwrap();
+/// assert!(cross_check(puzzle, 0..3, None).is_empty());
+/// assert!(cross_check(puzzle, 0..1, Some("3")).is_empty());
+/// ```
+pub fn cross_check<I>(puzzle: &Puzzle, seeds: I, input: Option<&str>) -> Vec<Mismatch>
+where
+    I: IntoIterator<Item = u64> + Clone,
+{
+    let mut mismatches = Vec::new();
+    for check in puzzle.checks {
+        param::set_current(params(check));
+        let mismatch = match input {
+            Some(input) => compare(puzzle, check, input, None),
+            None => seeds.clone().into_iter().find_map(|seed| {
+                let input = (check.generate)(&mut Rng::new(seed), 1);
+                compare(puzzle, check, &input, Some(seed))
+            }),
+        };
+        mismatches.extend(mismatch);
+    }
+    param::set_current(Values::new());
+    mismatches
+}
+
@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Way of finding the cycle of a sequence of states
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Strategy {
+    /// Remembers every state in a hash map: the fewest steps, but the most memory
+    Hash,
+    /// Floyd's tortoise and hare: constant memory, about three times the steps
+    Floyd,
+    /// Brent's teleporting tortoise: constant memory, fewer steps than Floyd's
+    Brent,
+}
+
+/// Shape of the sequence of states obtained by repeating a step from a start,
+/// which ends up looping once a state is reached again
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cycle {
+    /// Number of states before the first state of the cycle
+    pub tail: usize,
+    /// Number of states in the cycle
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Number of steps before reaching a state met before,
+    /// the first repetition of the start of the cycle
+    pub fn first_repeat(&self) -> usize {
+        self.tail + self.length
+    }
+
+    /// States of the cycle, in order from the first one reached
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::cycle::{detect, Strategy};
+    /// let step = |&x: &u32| if x < 7 { x + 1 } else { 4 };
+    /// let cycle = detect(0, step, Strategy::Brent);
+    /// assert_eq!(vec![4, 5, 6, 7], cycle.states(0, step));
+    /// ```
+    pub fn states<S, F>(&self, start: S, mut step: F) -> Vec<S>
+    where
+        S: Clone,
+        F: FnMut(&S) -> S,
+    {
+        let mut state = start;
+        for _ in 0..self.tail {
+            state = step(&state);
+        }
+        let mut states = Vec::with_capacity(self.length);
+        for _ in 0..self.length {
+            let next = step(&state);
+            states.push(state);
+            state = next;
+        }
+        states
+    }
+
+    /// State reached after the number of steps,
+    /// only going through the tail and the cycle once
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::cycle::{detect, Strategy};
+    /// let step = |&x: &u32| if x < 7 { x + 1 } else { 4 };
+    /// let cycle = detect(0, step, Strategy::Floyd);
+    /// assert_eq!(6, cycle.nth(0, step, 1_000_000_002));
+    /// ```
+    pub fn nth<S, F>(&self, start: S, mut step: F, n: usize) -> S
+    where
+        S: Clone,
+        F: FnMut(&S) -> S,
+    {
+        let steps = if n < self.tail {
+            n
+        } else {
+            self.tail + (n - self.tail) % self.length
+        };
+        let mut state = start;
+        for _ in 0..steps {
+            state = step(&state);
+        }
+        state
+    }
+}
+
+/// Finds the cycle by remembering when each state was first reached
+pub fn hashed<S, F>(start: S, mut step: F) -> Cycle
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::new();
+    let mut state = start;
+    for i in 0.. {
+        let next = step(&state);
+        if let Some(first) = seen.insert(state, i) {
+            return Cycle {
+                tail: first,
+                length: i - first,
+            };
+        }
+        state = next;
+    }
+    unreachable!("The sequence of states is infinite")
+}
+
+/// Finds the cycle with Floyd's algorithm,
+/// moving a hare twice as fast as a tortoise until they meet in the cycle
+pub fn floyd<S, F>(start: S, mut step: F) -> Cycle
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    // The hare is now a multiple of the length ahead,
+    // so both meet at the start of the cycle when moving at the same speed
+    let mut tail = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        tail += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+    Cycle { tail, length }
+}
+
+/// Finds the cycle with Brent's algorithm,
+/// moving the tortoise to the hare every power of two steps until the hare reaches it
+pub fn brent<S, F>(start: S, mut step: F) -> Cycle
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // With the hare one length ahead, both meet at the start of the cycle
+    let mut tail = 0;
+    tortoise = start.clone();
+    hare = start;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        tail += 1;
+    }
+    Cycle { tail, length }
+}
+
+/// Finds the cycle of the states obtained by repeating the step from the start
+///
+/// # Examples
+/// ```
+/// use advent_of_code::cycle::{detect, Cycle, Strategy};
+/// let step = |&x: &u64| (x * x + 1) % 1009;
+/// let cycle = detect(3, step, Strategy::Hash);
+/// assert_eq!(cycle, detect(3, step, Strategy::Floyd));
+/// assert_eq!(cycle, detect(3, step, Strategy::Brent));
+/// assert_eq!(Cycle { tail: 0, length: 1 }, detect(0, |&x: &u64| x, Strategy::Brent));
+/// ```
+pub fn detect<S, F>(start: S, step: F, strategy: Strategy) -> Cycle
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    match strategy {
+        Strategy::Hash => hashed(start, step),
+        Strategy::Floyd => floyd(start, step),
+        Strategy::Brent => brent(start, step),
+    }
+}
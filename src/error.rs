@@ -0,0 +1,125 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// Reason why a solver could not find the answer to a puzzle
+#[derive(Debug)]
+pub enum SolveError {
+    /// A line of the input could not be parsed
+    Parse {
+        /// Number of the line, starting from 1
+        line: usize,
+        /// Number of the character of the line where parsing failed, starting from 1
+        column: Option<usize>,
+        /// Content of the offending line or token
+        text: String,
+        /// Why it could not be parsed
+        reason: String,
+    },
+    /// The input is well-formed, but does not describe a valid puzzle
+    InvalidInput(String),
+    /// The puzzle has no solution for this input
+    NoSolution(String),
+    /// The input could not be read
+    Io(io::Error),
+    /// The solver stopped early, as it has been asked to
+    Cancelled,
+}
+
+impl SolveError {
+    /// Creates a parsing error on a line of the input
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::SolveError;
+    /// let e = SolveError::parse(3, "x/y", "Wrong parameters for exchange");
+    /// assert_eq!("line 3: Wrong parameters for exchange: \"x/y\"", e.to_string());
+    /// ```
+    pub fn parse<T: fmt::Display>(line: usize, text: &str, reason: T) -> Self {
+        SolveError::Parse {
+            line,
+            column: None,
+            text: text.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Creates a parsing error on a character of a line of the input
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::SolveError;
+    /// let e = SolveError::parse_at(2, 5, "set a", "Expected a value");
+    /// assert_eq!("line 2, column 5: Expected a value: \"set a\"", e.to_string());
+    /// ```
+    pub fn parse_at<T: fmt::Display>(line: usize, column: usize, text: &str, reason: T) -> Self {
+        SolveError::Parse {
+            line,
+            column: Some(column),
+            text: text.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl Clone for SolveError {
+    /// Clones the error. An I/O error keeps its kind and message,
+    /// but loses its source.
+    fn clone(&self) -> Self {
+        match *self {
+            SolveError::Parse {
+                line,
+                column,
+                ref text,
+                ref reason,
+            } => SolveError::Parse {
+                line,
+                column,
+                text: text.clone(),
+                reason: reason.clone(),
+            },
+            SolveError::InvalidInput(ref reason) => SolveError::InvalidInput(reason.clone()),
+            SolveError::NoSolution(ref reason) => SolveError::NoSolution(reason.clone()),
+            SolveError::Io(ref e) => SolveError::Io(io::Error::new(e.kind(), e.to_string())),
+            SolveError::Cancelled => SolveError::Cancelled,
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SolveError::Parse {
+                line,
+                column,
+                ref text,
+                ref reason,
+            } => {
+                write!(f, "line {}", line)?;
+                if let Some(column) = column {
+                    write!(f, ", column {}", column)?;
+                }
+                write!(f, ": {}: {:?}", reason, text)
+            }
+            SolveError::InvalidInput(ref reason) => write!(f, "invalid input: {}", reason),
+            SolveError::NoSolution(ref reason) => write!(f, "no solution: {}", reason),
+            SolveError::Io(ref e) => write!(f, "{}", e),
+            SolveError::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+impl Error for SolveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SolveError::Io(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SolveError {
+    fn from(e: io::Error) -> Self {
+        SolveError::Io(e)
+    }
+}
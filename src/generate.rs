@@ -0,0 +1,95 @@
+use Puzzle;
+
+/// Generates a random input in the format of a day,
+/// from a random number generator and a scale:
+/// 1 for inputs the size of the puzzle inputs, more for bigger ones
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+/// Pseudo-random number generator (SplitMix64),
+/// so that a seed always generates the same input on every platform
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Creates a generator from its seed
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::generate::Rng;
+    /// let (mut a, mut b) = (Rng::new(7), Rng::new(7));
+    /// assert_eq!(a.next_u64(), b.next_u64());
+    /// assert_ne!(a.next_u64(), Rng::new(8).next_u64());
+    /// ```
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    /// Next random number, uniformly distributed
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Random number between `low` and `high`, both included
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::generate::Rng;
+    /// let mut rng = Rng::new(0);
+    /// assert!((0..100).map(|_| rng.range(-3, 3)).all(|x| -3 <= x && x <= 3));
+    /// ```
+    pub fn range(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low <= high, "The range {}..={} is empty", low, high);
+        let span = (high - low) as u64 + 1;
+        low + (self.next_u64() % span) as i64
+    }
+
+    /// Random index below `n`
+    pub fn below(&mut self, n: usize) -> usize {
+        self.range(0, n as i64 - 1) as usize
+    }
+
+    /// True once in `n` times on average
+    pub fn one_in(&mut self, n: usize) -> bool {
+        self.below(n) == 0
+    }
+
+    /// Random element of a non-empty slice
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Shuffles the slice in place (Fisher-Yates)
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i + 1);
+            items.swap(i, j);
+        }
+    }
+
+    /// Random word of lowercase letters
+    pub fn word(&mut self, len: usize) -> String {
+        (0..len).map(|_| (b'a' + self.below(26) as u8) as char).collect()
+    }
+}
+
+/// Generates a random input for the puzzle,
+/// always the same one for a given seed and scale
+///
+/// # Examples
+/// ```
+/// use advent_of_code::generate::generate;
+/// use advent_of_code::get_puzzle;
+/// let puzzle = get_puzzle(2017, 13).unwrap();
+/// let input = generate(puzzle, 42, 1);
+/// assert_eq!(input, generate(puzzle, 42, 1));
+/// assert!((puzzle.parse)(&input).is_ok());
+/// ```
+pub fn generate(puzzle: &Puzzle, seed: u64, scale: usize) -> String {
+    (puzzle.generate)(&mut Rng::new(seed), scale.max(1))
+}
@@ -0,0 +1,313 @@
+use std::collections::{HashMap, VecDeque};
+
+/// Directed graph whose nodes are numbered from 0,
+/// storing the list of the successors of each node
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Graph {
+    edges: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    /// Creates a graph of `nodes` nodes, without edges
+    pub fn new(nodes: usize) -> Self {
+        Graph {
+            edges: vec![vec![]; nodes],
+        }
+    }
+
+    /// Number of nodes
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Whether the graph has no node
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    /// Adds an edge from a node to another,
+    /// adding the nodes which do not exist yet
+    pub fn add_edge(&mut self, from: usize, to: usize) {
+        let nodes = from.max(to) + 1;
+        if self.edges.len() < nodes {
+            self.edges.resize(nodes, vec![]);
+        }
+        self.edges[from].push(to);
+    }
+
+    /// Adds an edge in both directions
+    pub fn add_undirected_edge(&mut self, a: usize, b: usize) {
+        self.add_edge(a, b);
+        if a != b {
+            self.add_edge(b, a);
+        }
+    }
+
+    /// Successors of the node, in the order their edges were added
+    pub fn neighbors(&self, node: usize) -> &[usize] {
+        &self.edges[node]
+    }
+
+    /// Nodes which no edge leads to, in increasing order
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::graph::Graph;
+    /// let mut graph = Graph::new(4);
+    /// graph.add_edge(0, 1);
+    /// graph.add_edge(2, 0);
+    /// assert_eq!(vec![2, 3], graph.roots());
+    /// ```
+    pub fn roots(&self) -> Vec<usize> {
+        let mut reached = vec![false; self.len()];
+        for &to in self.edges.iter().flatten() {
+            reached[to] = true;
+        }
+        (0..self.len()).filter(|&n| !reached[n]).collect()
+    }
+
+    /// Nodes reachable from the start, in breadth-first order,
+    /// with the number of edges on the shortest path leading to them,
+    /// or none if the start is not a node of the graph
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::graph::Graph;
+    /// let mut graph = Graph::new(5);
+    /// graph.add_edge(0, 1);
+    /// graph.add_edge(1, 2);
+    /// graph.add_edge(0, 2);
+    /// graph.add_edge(3, 0);
+    /// assert_eq!(vec![(0, 0), (1, 1), (2, 1)], graph.bfs(0));
+    /// assert!(graph.bfs(5).is_empty());
+    /// ```
+    pub fn bfs(&self, start: usize) -> Vec<(usize, usize)> {
+        let mut seen = vec![false; self.len()];
+        let mut order = Vec::new();
+        if start >= self.len() {
+            return order;
+        }
+        let mut queue = VecDeque::new();
+        seen[start] = true;
+        queue.push_back((start, 0));
+        while let Some((node, distance)) = queue.pop_front() {
+            order.push((node, distance));
+            for &next in &self.edges[node] {
+                if !seen[next] {
+                    seen[next] = true;
+                    queue.push_back((next, distance + 1));
+                }
+            }
+        }
+        order
+    }
+
+    /// Nodes reachable from the start, in depth-first order:
+    /// every node comes before the nodes first discovered through it.
+    /// There are none if the start is not a node of the graph.
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::graph::Graph;
+    /// let mut graph = Graph::new(5);
+    /// graph.add_edge(0, 1);
+    /// graph.add_edge(1, 2);
+    /// graph.add_edge(0, 3);
+    /// graph.add_edge(3, 1);
+    /// assert_eq!(vec![0, 1, 2, 3], graph.dfs(0));
+    /// assert!(graph.dfs(5).is_empty());
+    /// ```
+    pub fn dfs(&self, start: usize) -> Vec<usize> {
+        let mut seen = vec![false; self.len()];
+        let mut order = Vec::new();
+        if start >= self.len() {
+            return order;
+        }
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            if seen[node] {
+                continue;
+            }
+            seen[node] = true;
+            order.push(node);
+            stack.extend(self.edges[node].iter().rev().filter(|&&n| !seen[n]));
+        }
+        order
+    }
+
+    /// Groups the nodes linked by edges, whatever their direction.
+    /// Each group is sorted, and the groups are ordered by their smallest node.
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::graph::Graph;
+    /// let mut graph = Graph::new(6);
+    /// graph.add_edge(0, 2);
+    /// graph.add_edge(4, 2);
+    /// graph.add_edge(3, 5);
+    /// assert_eq!(vec![vec![0, 2, 4], vec![1], vec![3, 5]], graph.components());
+    /// ```
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        let mut sets = UnionFind::new(self.len());
+        for (from, successors) in self.edges.iter().enumerate() {
+            for &to in successors {
+                sets.union(from, to);
+            }
+        }
+        let mut index = HashMap::new();
+        let mut components: Vec<Vec<usize>> = Vec::new();
+        for node in 0..self.len() {
+            let root = sets.find(node);
+            let i = *index.entry(root).or_insert_with(|| {
+                components.push(vec![]);
+                components.len() - 1
+            });
+            components[i].push(node);
+        }
+        components
+    }
+
+    /// Number of nodes of each component, in the order of `components`
+    pub fn component_sizes(&self) -> Vec<usize> {
+        self.components().iter().map(Vec::len).collect()
+    }
+}
+
+/// Partition of the numbers from 0 into disjoint sets,
+/// which can be merged and queried in almost constant time
+#[derive(Clone, Debug)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    count: usize,
+}
+
+impl UnionFind {
+    /// Creates `n` sets, each holding a single element
+    pub fn new(n: usize) -> Self {
+        UnionFind {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            count: n,
+        }
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Whether the graph has no node
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Number of disjoint sets
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Representative of the set of the element,
+    /// the same for every element of the set
+    pub fn find(&mut self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            // Path halving: every other node points to its grandparent
+            self.parent[x] = self.parent[self.parent[x]];
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Merges the sets of both elements.
+    /// Returns false if they already were in the same set.
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::graph::UnionFind;
+    /// let mut sets = UnionFind::new(5);
+    /// assert!(sets.union(0, 1));
+    /// assert!(sets.union(3, 1));
+    /// assert!(!sets.union(0, 3));
+    /// assert!(sets.same(0, 3) && !sets.same(0, 2));
+    /// assert_eq!((3, 3), (sets.count(), sets.size(1)));
+    /// ```
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let (small, large) = if self.size[a] < self.size[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parent[small] = large;
+        self.size[large] += self.size[small];
+        self.count -= 1;
+        true
+    }
+
+    /// Whether both elements are in the same set
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of elements of the set of the element
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+}
+
+/// Numbers the nodes of a graph known by their names,
+/// in the order the names are first met
+#[derive(Clone, Debug, Default)]
+pub struct Names {
+    ids: HashMap<String, usize>,
+    names: Vec<String>,
+}
+
+impl Names {
+    pub fn new() -> Self {
+        Names::default()
+    }
+
+    /// Number of the node, numbering it if the name is new
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::graph::Names;
+    /// let mut names = Names::new();
+    /// assert_eq!((0, 1, 0), (names.id("pbga"), names.id("xhth"), names.id("pbga")));
+    /// assert_eq!("xhth", names.name(1));
+    /// assert_eq!(None, names.get("ebii"));
+    /// ```
+    pub fn id(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), self.names.len() - 1);
+        self.names.len() - 1
+    }
+
+    /// Number of the node, if the name was already met
+    pub fn get(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).cloned()
+    }
+
+    /// Name of the node
+    pub fn name(&self, id: usize) -> &str {
+        &self.names[id]
+    }
+
+    /// Number of named nodes
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Whether the graph has no node
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
@@ -0,0 +1,415 @@
+use std::collections::hash_map::{self, HashMap};
+use std::iter::FromIterator;
+use std::ops::{Add, Index, IndexMut, Sub};
+use SolveError;
+
+/// Coordinates on a grid, with `x` growing to the right
+/// and `y` growing downwards, as in the lines of a text map
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    /// Manhattan distance to the origin
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::grid::Point;
+    /// assert_eq!(5, Point::new(-2, 3).manhattan());
+    /// ```
+    pub fn manhattan(self) -> i64 {
+        self.x.abs() + self.y.abs()
+    }
+
+    /// The 4 points sharing a side with this one, clockwise from the one above
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::grid::Point;
+    /// let n = Point::new(0, 0).neighbors4();
+    /// assert_eq!([Point::new(0, -1), Point::new(1, 0), Point::new(0, 1), Point::new(-1, 0)], n);
+    /// ```
+    pub fn neighbors4(self) -> [Point; 4] {
+        Dir::ALL.map(|d| self + d)
+    }
+
+    /// The 8 points sharing a side or a corner with this one,
+    /// clockwise from the one above
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::grid::Point;
+    /// let p = Point::new(3, 4);
+    /// assert!(p.neighbors8().iter().all(|&n| n != p && (n - p).x.abs() <= 1 && (n - p).y.abs() <= 1));
+    /// ```
+    pub fn neighbors8(self) -> [Point; 8] {
+        [
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+            (-1, -1),
+        ].map(|(x, y)| self + Point::new(x, y))
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+/// Moves the point one step in the direction
+impl Add<Dir> for Point {
+    type Output = Point;
+
+    fn add(self, dir: Dir) -> Point {
+        self + dir.offset()
+    }
+}
+
+/// Direction of movement on a grid
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// Change of direction, relative to the current one
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Turn {
+    Left,
+    Right,
+    Reverse,
+}
+
+impl Dir {
+    /// Every direction, clockwise from up
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    /// Offset of a step in the direction
+    pub fn offset(self) -> Point {
+        match self {
+            Dir::Up => Point::new(0, -1),
+            Dir::Right => Point::new(1, 0),
+            Dir::Down => Point::new(0, 1),
+            Dir::Left => Point::new(-1, 0),
+        }
+    }
+
+    /// Whether the direction goes along a column
+    pub fn is_vertical(self) -> bool {
+        self == Dir::Up || self == Dir::Down
+    }
+
+    /// Direction faced after turning
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::grid::{Dir, Turn};
+    /// assert_eq!(Dir::Left, Dir::Up.turn(Turn::Left));
+    /// assert_eq!(Dir::Up, Dir::Left.turn(Turn::Right));
+    /// assert_eq!(Dir::Down, Dir::Up.turn(Turn::Reverse));
+    /// ```
+    pub fn turn(self, turn: Turn) -> Dir {
+        let quarters = match turn {
+            Turn::Right => 1,
+            Turn::Reverse => 2,
+            Turn::Left => 3,
+        };
+        Dir::ALL[(self as usize + quarters) % 4]
+    }
+}
+
+/// Grid storing only some of its cells, without bounds,
+/// such as the walkable cells of a map or a grid growing as it is explored
+#[derive(Clone, Debug, Default)]
+pub struct Sparse<T> {
+    cells: HashMap<Point, T>,
+}
+
+impl<T> Sparse<T> {
+    pub fn new() -> Self {
+        Sparse {
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Parses a character map, keeping the cells for which `cell` returns a value.
+    /// The first character of the first line is at the origin.
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::grid::{Point, Sparse};
+    /// let grid = Sparse::parse("..#\n#..", |c| if c == '#' { Some(true) } else { None });
+    /// assert_eq!(2, grid.len());
+    /// assert!(grid.contains(Point::new(2, 0)) && grid.contains(Point::new(0, 1)));
+    /// ```
+    pub fn parse<F>(s: &str, cell: F) -> Self
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        s.lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(move |(x, c)| (Point::new(x as i64, y as i64), c))
+            })
+            .filter_map(|(p, c)| cell(c).map(|v| (p, v)))
+            .collect()
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    /// Sets the value of the cell, returning its previous one
+    pub fn insert(&mut self, p: Point, value: T) -> Option<T> {
+        self.cells.insert(p, value)
+    }
+
+    pub fn remove(&mut self, p: Point) -> Option<T> {
+        self.cells.remove(&p)
+    }
+
+    /// Number of stored cells
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Stored cells with their coordinates, in no particular order
+    pub fn iter(&self) -> hash_map::Iter<'_, Point, T> {
+        self.cells.iter()
+    }
+
+    /// Smallest and largest coordinates of the stored cells,
+    /// or None if the grid is empty
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let mut points = self.cells.keys();
+        let &first = points.next()?;
+        Some(points.fold((first, first), |(min, max), p| {
+            (
+                Point::new(min.x.min(p.x), min.y.min(p.y)),
+                Point::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        }))
+    }
+
+    /// Renders the rectangle holding every stored cell as a character map,
+    /// `cell` being given None for the cells which are not stored
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::grid::Sparse;
+    /// let grid = Sparse::parse("  |\n  +-A", |c| if c == ' ' { None } else { Some(c) });
+    /// assert_eq!("|  \n+-A", grid.render(|c| *c.unwrap_or(&' ')));
+    /// ```
+    pub fn render<F>(&self, cell: F) -> String
+    where
+        F: Fn(Option<&T>) -> char,
+    {
+        let (min, max) = match self.bounds() {
+            Some(bounds) => bounds,
+            None => return String::new(),
+        };
+        let rows: Vec<String> = (min.y..max.y + 1)
+            .map(|y| {
+                (min.x..max.x + 1)
+                    .map(|x| cell(self.get(Point::new(x, y))))
+                    .collect()
+            })
+            .collect();
+        rows.join("\n")
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for Sparse<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        Sparse {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Rectangular grid storing every one of its cells, row by row,
+/// with the origin at its top-left corner
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dense<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Dense<T> {
+    /// Creates a grid whose cells all hold the value
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Dense {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Dense<T> {
+    /// Parses a rectangular character map, converting every character with `cell`
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::grid::{Dense, Point};
+    /// let grid = Dense::parse("#.\n.#", |c| Some(c == '#')).unwrap();
+    /// assert_eq!((2, 2), (grid.width(), grid.height()));
+    /// assert!(grid[Point::new(1, 1)] && !grid[Point::new(1, 0)]);
+    /// assert!(Dense::parse("#.\n.", |c| Some(c == '#')).is_err());
+    /// assert!(Dense::parse("#x", |c| if c == 'x' { None } else { Some(c) }).is_err());
+    /// ```
+    pub fn parse<F>(s: &str, cell: F) -> Result<Self, SolveError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in s.lines().enumerate() {
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(x, c)| {
+                    cell(c).ok_or_else(|| {
+                        SolveError::InvalidInput(format!(
+                            "Unexpected character {:?} on line {}, column {}",
+                            c,
+                            y + 1,
+                            x + 1
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<T>, SolveError>>()?;
+            match width {
+                Some(w) if w != row.len() => {
+                    return Err(SolveError::InvalidInput(format!(
+                        "Line {} has {} cells instead of {}",
+                        y + 1,
+                        row.len(),
+                        w
+                    )))
+                }
+                _ => width = Some(row.len()),
+            }
+            cells.extend(row);
+            height += 1;
+        }
+        Ok(Dense {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether the point is inside the grid
+    pub fn contains(&self, p: Point) -> bool {
+        0 <= p.x && (p.x as usize) < self.width && 0 <= p.y && (p.y as usize) < self.height
+    }
+
+    /// Position of the cell in the storage, or None if the point is outside the grid
+    fn position(&self, p: Point) -> Option<usize> {
+        if self.contains(p) {
+            Some(p.y as usize * self.width + p.x as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Value of the cell, or None if the point is outside the grid
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.position(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.position(p).map(move |i| &mut self.cells[i])
+    }
+
+    /// Every point of the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width as i64;
+        (0..self.cells.len() as i64).map(move |i| Point::new(i % width, i / width))
+    }
+
+    /// Renders the grid as a character map
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::grid::{Dense, Point};
+    /// let mut grid = Dense::new(3, 2, false);
+    /// grid[Point::new(2, 1)] = true;
+    /// assert_eq!("...\n..#", grid.render(|&b| if b { '#' } else { '.' }));
+    /// ```
+    pub fn render<F>(&self, cell: F) -> String
+    where
+        F: Fn(&T) -> char,
+    {
+        let rows: Vec<String> = self.cells
+            .chunks(self.width.max(1))
+            .map(|row| row.iter().map(&cell).collect())
+            .collect();
+        rows.join("\n")
+    }
+}
+
+impl<T> Index<Point> for Dense<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", p))
+    }
+}
+
+impl<T> IndexMut<Point> for Dense<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", p))
+    }
+}
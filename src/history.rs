@@ -0,0 +1,332 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use bench::Stats;
+use runner::{format_duration, Run};
+
+/// File the runs of the binary are recorded in
+pub const DEFAULT_FILE: &str = "history.csv";
+
+/// Header of the history file
+pub const CSV_HEADER: &str =
+    "timestamp,revision,year,day,part,input,answer_hash,input_ns,parse_ns,solve_ns";
+
+/// Run of a solver, as recorded in the history file
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    /// Git revision of the code which ran, if known
+    pub revision: Option<String>,
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    /// Path of the input file
+    pub input: String,
+    /// Hash of the answer, or None if the run failed
+    pub answer_hash: Option<u64>,
+    pub input_time: Duration,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// Hashes an answer with FNV-1a,
+/// which unlike the hasher of the standard library never changes between versions
+///
+/// # Examples
+/// ```
+/// use advent_of_code::history::hash_answer;
+/// assert_eq!(0xaf63_dc4c_8601_ec8c, hash_answer("a"));
+/// assert_ne!(hash_answer("1031"), hash_answer("1030"));
+/// ```
+pub fn hash_answer(answer: &str) -> u64 {
+    answer.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Short hash of the current git revision,
+/// or None outside of a repository or without git
+pub fn current_revision() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+    let revision = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    if output.status.success() && !revision.is_empty() {
+        Some(revision)
+    } else {
+        None
+    }
+}
+
+/// Quotes a CSV field if it holds a separator or a quote
+fn csv_field(s: &str) -> String {
+    if s.contains(',') || s.contains('"') {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Splits a CSV line into its fields, unquoting them
+fn csv_fields(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            c => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields
+}
+
+/// Converts a number of nanoseconds into a duration
+fn from_nanos(n: u64) -> Duration {
+    Duration::new(n / 1_000_000_000, (n % 1_000_000_000) as u32)
+}
+
+impl Record {
+    /// Records a run of the binary
+    pub fn from_run(run: &Run, timestamp: u64, revision: Option<&str>) -> Self {
+        Record {
+            timestamp,
+            revision: revision.map(String::from),
+            year: run.year,
+            day: run.day,
+            part: run.part,
+            input: run.input.clone(),
+            answer_hash: run.answer
+                .as_ref()
+                .ok()
+                .map(|answer| hash_answer(&answer.to_string())),
+            input_time: run.input_time,
+            parse_time: run.parse_time,
+            solve_time: run.solve_time,
+        }
+    }
+
+    /// Renders the record as a line of the history file
+    pub fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{},{}",
+            self.timestamp,
+            csv_field(self.revision.as_ref().map_or("", |r| r.as_str())),
+            self.year,
+            self.day,
+            self.part,
+            csv_field(&self.input),
+            self.answer_hash.map_or(String::new(), |h| format!("{:016x}", h)),
+            self.input_time.as_nanos(),
+            self.parse_time.as_nanos(),
+            self.solve_time.as_nanos()
+        )
+    }
+
+    /// Parses a line of the history file
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::history::Record;
+    /// let line = r#"1700000000,4f2a9c1,2017,7,1,"input/2017/day7/a,b",00000000000004d2,10,20,30"#;
+    /// let record = Record::parse(line).unwrap();
+    /// assert_eq!("input/2017/day7/a,b", record.input);
+    /// assert_eq!(Some(1234), record.answer_hash);
+    /// assert_eq!(line, record.to_csv());
+    /// assert!(Record::parse("1700000000,,2017,7").is_err());
+    /// ```
+    pub fn parse(line: &str) -> Result<Self, String> {
+        let fields = csv_fields(line);
+        if fields.len() != 10 {
+            return Err(format!("Expected 10 fields, found {}", fields.len()));
+        }
+        let int = |i: usize, name: &str| {
+            fields[i]
+                .parse::<u64>()
+                .map_err(|e| format!("Could not parse {} as int: {}", name, e))
+        };
+        let answer_hash = match fields[6].as_str() {
+            "" => None,
+            hash => Some(
+                u64::from_str_radix(hash, 16)
+                    .map_err(|e| format!("Could not parse the answer hash: {}", e))?,
+            ),
+        };
+        Ok(Record {
+            timestamp: int(0, "timestamp")?,
+            revision: Some(fields[1].clone()).filter(|r| !r.is_empty()),
+            year: int(2, "year")? as u32,
+            day: int(3, "day")? as u32,
+            part: int(4, "part")? as u32,
+            input: fields[5].clone(),
+            answer_hash,
+            input_time: from_nanos(int(7, "input time")?),
+            parse_time: from_nanos(int(8, "parse time")?),
+            solve_time: from_nanos(int(9, "solve time")?),
+        })
+    }
+}
+
+/// Appends the runs to the history file,
+/// writing the header first if the file is new or empty.
+pub fn append(filename: &str, runs: &[Run], revision: Option<&str>) -> io::Result<()> {
+    let mut f = OpenOptions::new().create(true).append(true).open(filename)?;
+    if f.metadata()?.len() == 0 {
+        writeln!(f, "{}", CSV_HEADER)?;
+    }
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    for run in runs {
+        writeln!(f, "{}", Record::from_run(run, timestamp, revision).to_csv())?;
+    }
+    Ok(())
+}
+
+/// Reads every record of the history file, in the order they were appended
+pub fn read(filename: &str) -> io::Result<Vec<Record>> {
+    let mut content = String::new();
+    File::open(filename)?.read_to_string(&mut content)?;
+    content
+        .lines()
+        .enumerate()
+        .filter(|&(_, line)| !line.trim().is_empty() && line != CSV_HEADER)
+        .map(|(i, line)| {
+            Record::parse(line).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{} line {}: {}", filename, i + 1, e),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Change of the last run of a solver compared to the run recorded before it
+#[derive(Debug, PartialEq)]
+pub enum Flag {
+    /// The solver became slower, by the provided percentage
+    Slower(f64),
+    /// The answer is not the same anymore, or the solver started or stopped failing
+    Changed,
+}
+
+impl fmt::Display for Flag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Flag::Slower(percent) => write!(f, "SLOWER by {:.0}%", percent),
+            Flag::Changed => write!(f, "CHANGED answer"),
+        }
+    }
+}
+
+/// Evolution of the solve time of a part on an input over its recorded runs
+pub struct Trend {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub input: String,
+    /// Statistics over the solve times of every recorded run
+    pub stats: Stats,
+    /// Solve time of the last run
+    pub last: Duration,
+    /// Changes of the last run compared to the previous one
+    pub flags: Vec<Flag>,
+}
+
+impl fmt::Display for Trend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:>4} {:>3} {:>4}  {}  {} runs  min {}  median {}  last {}",
+            self.year,
+            self.day,
+            self.part,
+            self.input,
+            self.stats.runs,
+            format_duration(self.stats.min),
+            format_duration(self.stats.median),
+            format_duration(self.last)
+        )?;
+        for flag in &self.flags {
+            write!(f, "  {}", flag)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares the last run of a solver with the one before it,
+/// flagging it if it is more than `threshold` percent slower
+/// or if its answer changed
+fn flags(previous: &Record, last: &Record, threshold: f64) -> Vec<Flag> {
+    let mut flags = Vec::new();
+    let (previous_time, last_time) = (previous.solve_time.as_nanos(), last.solve_time.as_nanos());
+    if previous_time > 0 {
+        let percent = (last_time as f64 / previous_time as f64 - 1.) * 100.;
+        if percent > threshold {
+            flags.push(Flag::Slower(percent));
+        }
+    }
+    if previous.answer_hash != last.answer_hash {
+        flags.push(Flag::Changed);
+    }
+    flags
+}
+
+/// Groups the records by year, day, part and input,
+/// and computes the trend of each group
+///
+/// # Examples
+/// ```
+/// use advent_of_code::history::{trends, Flag, Record};
+/// let record = |answer, ms| Record::parse(&format!(
+///     "1700000000,,2017,7,1,input/2017/day7,{},0,0,{}", answer, ms * 1_000_000
+/// )).unwrap();
+/// let records = vec![record("01", 10), record("01", 11), record("02", 15)];
+/// let all = trends(&records, 20.);
+/// assert_eq!(1, all.len());
+/// assert_eq!(3, all[0].stats.runs);
+/// assert!(matches!(all[0].flags[0], Flag::Slower(p) if p > 36. && p < 37.));
+/// assert_eq!(Flag::Changed, all[0].flags[1]);
+/// assert!(trends(&records[..2], 20.)[0].flags.is_empty());
+/// ```
+pub fn trends(records: &[Record], threshold: f64) -> Vec<Trend> {
+    let mut groups: BTreeMap<(u32, u32, u32, &str), Vec<&Record>> = BTreeMap::new();
+    for r in records {
+        groups
+            .entry((r.year, r.day, r.part, &r.input))
+            .or_default()
+            .push(r);
+    }
+    groups
+        .into_iter()
+        .map(|((year, day, part, input), runs)| {
+            let samples: Vec<Duration> = runs.iter().map(|r| r.solve_time).collect();
+            let last = runs[runs.len() - 1];
+            let flags = match runs.len() {
+                1 => vec![],
+                n => flags(runs[n - 2], last, threshold),
+            };
+            Trend {
+                year,
+                day,
+                part,
+                input: input.to_string(),
+                stats: Stats::from_samples(&samples).expect("Every group has a run"),
+                last: last.solve_time,
+                flags,
+            }
+        })
+        .collect()
+}
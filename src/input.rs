@@ -0,0 +1,119 @@
+use std::fmt;
+use std::ops::Deref;
+use SolveError;
+
+/// Byte order mark written at the start of the file by some editors
+const BOM: char = '\u{feff}';
+
+/// Puzzle input, normalized so that every parser sees the same text
+/// whichever editor or platform saved the file:
+///
+/// - the byte order mark is stripped,
+/// - `\r\n` and lone `\r` line endings become `\n`,
+/// - the trailing newlines are removed, so that the last line is not followed
+///   by an empty one, while the spaces of the lines are kept.
+///
+/// The lines containing tabs are recorded, as they break the alignment
+/// of the grids.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Input {
+    text: String,
+    tab_lines: Vec<usize>,
+}
+
+impl Input {
+    /// Normalizes the raw content of an input file
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::input::Input;
+    /// let input = Input::normalize("\u{feff}5 1\t9\r\n7 5 3\r\n\r\n");
+    /// assert_eq!("5 1\t9\n7 5 3", input.as_str());
+    /// assert_eq!(&[1], input.tab_lines());
+    /// assert_eq!(" |\n +", Input::normalize(" |\r +\n").as_str());
+    /// ```
+    pub fn normalize(raw: &str) -> Self {
+        let raw = raw.strip_prefix(BOM).unwrap_or(raw);
+        let text = raw
+            .replace("\r\n", "\n")
+            .replace('\r', "\n")
+            .trim_end_matches('\n')
+            .to_string();
+        let tab_lines = text.lines()
+            .enumerate()
+            .filter(|&(_, line)| line.contains('\t'))
+            .map(|(i, _)| i + 1)
+            .collect();
+        Input { text, tab_lines }
+    }
+
+    /// The normalized text
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Numbers of the lines containing tabs, starting from 1
+    pub fn tab_lines(&self) -> &[usize] {
+        &self.tab_lines
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// Checks every line of the input with the provided function,
+/// reporting the first line it rejects along with the reason.
+/// Validators use it to describe the expected structure of the input,
+/// which must not be made only of whitespace.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::input::check_lines;
+/// let digits = |line: &str| {
+///     if line.chars().all(|c| c.is_ascii_digit()) {
+///         Ok(())
+///     } else {
+///         Err("Expected only digits")
+///     }
+/// };
+/// assert!(check_lines("1122\n1234", digits).is_ok());
+/// let e = check_lines("1122\n12a4", digits).unwrap_err();
+/// assert_eq!("line 2: Expected only digits: \"12a4\"", e.to_string());
+/// assert!(check_lines("", digits).is_err());
+/// ```
+pub fn check_lines<F, T>(s: &str, check: F) -> Result<(), SolveError>
+where
+    F: Fn(&str) -> Result<(), T>,
+    T: fmt::Display,
+{
+    if s.trim().is_empty() {
+        return Err(SolveError::InvalidInput(String::from("The input is empty")));
+    }
+    for (i, line) in s.lines().enumerate() {
+        check(line).map_err(|reason| SolveError::parse(i + 1, line, reason))?;
+    }
+    Ok(())
+}
+
+/// Rejects the input if any of its lines contains a tab,
+/// for the inputs whose columns are meaningful
+pub fn check_no_tabs(s: &str) -> Result<(), SolveError> {
+    check_lines(s, |line| {
+        if line.contains('\t') {
+            Err("Tabs break the alignment of the columns, use spaces instead")
+        } else {
+            Ok(())
+        }
+    })
+}
@@ -1,95 +1,114 @@
-pub mod day1;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
+pub mod answer;
+pub mod bench;
+pub mod cancel;
+pub mod crosscheck;
+pub mod cycle;
+pub mod error;
+pub mod generate;
+pub mod graph;
+pub mod grid;
+pub mod history;
+pub mod input;
+pub mod param;
+pub mod permutation;
+pub mod progress;
+pub mod puzzle;
+pub mod report;
+pub mod runner;
+pub mod scan;
+pub mod verify;
+pub mod y2017;
 
 use std::fs::File;
 use std::io::Read;
-extern crate regex;
 
-pub type Solver = fn(&str) -> String;
+pub use answer::Answer;
+pub use error::SolveError;
+pub use input::Input;
+pub use puzzle::Puzzle;
 
-/// Get the solver function correpsonding to the day and part
-pub fn get_solver(day: u32, part: u32) -> Option<Solver> {
-    match (day, part) {
-        (1, 1) => Some(day1::one),
-        (1, 2) => Some(day1::two),
-        (2, 1) => Some(day2::one),
-        (2, 2) => Some(day2::two),
-        (3, 1) => Some(day3::one),
-        (3, 2) => Some(day3::two),
-        (4, 1) => Some(day4::one),
-        (4, 2) => Some(day4::two),
-        (5, 1) => Some(day5::one),
-        (5, 2) => Some(day5::two),
-        (6, 1) => Some(day6::one),
-        (6, 2) => Some(day6::two),
-        (7, 1) => Some(day7::one),
-        (7, 2) => Some(day7::two),
-        (8, 1) => Some(day8::one),
-        (8, 2) => Some(day8::two),
-        (9, 1) => Some(day9::one),
-        (9, 2) => Some(day9::two),
-        (10, 1) => Some(day10::one),
-        (10, 2) => Some(day10::two),
-        (11, 1) => Some(day11::one),
-        (11, 2) => Some(day11::two),
-        (12, 1) => Some(day12::one),
-        (12, 2) => Some(day12::two),
-        (13, 1) => Some(day13::one),
-        (13, 2) => Some(day13::two),
-        (14, 1) => Some(day14::one),
-        (14, 2) => Some(day14::two),
-        (15, 1) => Some(day15::one),
-        (15, 2) => Some(day15::two),
-        (16, 1) => Some(day16::one),
-        (16, 2) => Some(day16::two),
-        (17, 1) => Some(day17::one),
-        (17, 2) => Some(day17::two),
-        (18, 1) => Some(day18::one),
-        (18, 2) => Some(day18::two),
-        (19, 1) => Some(day19::one),
-        (19, 2) => Some(day19::two),
-        (20, 1) => Some(day20::one),
-        (20, 2) => Some(day20::two),
-        (21, 1) => Some(day21::one),
-        (21, 2) => Some(day21::two),
-        (22, 1) => Some(day22::one),
-        (22, 2) => Some(day22::two),
-        (23, 1) => Some(day23::one),
-        (23, 2) => Some(day23::two),
-        (24, 1) => Some(day24::one),
-        (24, 2) => Some(day24::two),
-        (25, 1) => Some(day25::one),
-        _ => None,
-    }
+pub type Solver = fn(&str) -> Result<Answer, SolveError>;
+
+/// Every puzzle solved in the crate, ordered by year then by day
+pub static PUZZLES: &[&Puzzle] = &[
+    &y2017::day1::PUZZLE,
+    &y2017::day2::PUZZLE,
+    &y2017::day3::PUZZLE,
+    &y2017::day4::PUZZLE,
+    &y2017::day5::PUZZLE,
+    &y2017::day6::PUZZLE,
+    &y2017::day7::PUZZLE,
+    &y2017::day8::PUZZLE,
+    &y2017::day9::PUZZLE,
+    &y2017::day10::PUZZLE,
+    &y2017::day11::PUZZLE,
+    &y2017::day12::PUZZLE,
+    &y2017::day13::PUZZLE,
+    &y2017::day14::PUZZLE,
+    &y2017::day15::PUZZLE,
+    &y2017::day16::PUZZLE,
+    &y2017::day17::PUZZLE,
+    &y2017::day18::PUZZLE,
+    &y2017::day19::PUZZLE,
+    &y2017::day20::PUZZLE,
+    &y2017::day21::PUZZLE,
+    &y2017::day22::PUZZLE,
+    &y2017::day23::PUZZLE,
+    &y2017::day24::PUZZLE,
+    &y2017::day25::PUZZLE,
+];
+
+/// Get the description of the puzzle of the day
+pub fn get_puzzle(year: u32, day: u32) -> Option<&'static Puzzle> {
+    PUZZLES
+        .iter()
+        .find(|p| p.year == year && p.day == day)
+        .cloned()
+}
+
+/// Get the solver function correpsonding to the year, day and part
+///
+/// # Examples
+/// ```
+/// use advent_of_code::get_solver;
+/// assert!(get_solver(2017, 7, 2).is_some());
+/// assert!(get_solver(2017, 25, 2).is_none());
+/// assert!(get_solver(2017, 26, 1).is_none());
+/// assert!(get_solver(2016, 7, 2).is_none());
+/// ```
+pub fn get_solver(year: u32, day: u32, part: u32) -> Option<Solver> {
+    get_puzzle(year, day)?.solver(part)
 }
 
-/// Read the content of the file
-pub fn get_input(filename: &str) -> Result<String, std::io::Error> {
+/// Most recent year with solved puzzles,
+/// used when the year is not given on the command line
+///
+/// # Examples
+/// ```
+/// use advent_of_code::latest_year;
+/// assert_eq!(2017, latest_year());
+/// ```
+pub fn latest_year() -> u32 {
+    PUZZLES
+        .iter()
+        .map(|p| p.year)
+        .max()
+        .expect("At least one puzzle is solved")
+}
+
+/// Name of the input file standing for the standard input
+pub const STDIN: &str = "-";
+
+/// Read the content of the file,
+/// or of the standard input if the file name is `-`,
+/// and normalize it so that it does not depend on how the file has been saved
+pub fn get_input(filename: &str) -> Result<Input, std::io::Error> {
     let mut contents = String::new();
-    let mut f = File::open(filename)?;
-    f.read_to_string(&mut contents)?;
-    Ok(contents)
+    if filename == STDIN {
+        std::io::stdin().read_to_string(&mut contents)?;
+    } else {
+        let mut f = File::open(filename)?;
+        f.read_to_string(&mut contents)?;
+    }
+    Ok(Input::normalize(&contents))
 }
@@ -1,54 +1,628 @@
 extern crate advent_of_code;
-use advent_of_code::{get_input, get_solver, Solver};
+use advent_of_code::bench::{append_csv, bench};
+use advent_of_code::crosscheck::cross_check;
+use advent_of_code::generate::generate;
+use advent_of_code::history::{self, current_revision};
+use advent_of_code::runner::{format_duration, format_table, list_inputs, read_input, run_parallel,
+                             run_part, Failure, Options, Run};
+use advent_of_code::param::{self, parse_assignment};
+use advent_of_code::report::{render, Document, Settings};
+use advent_of_code::verify::{parse_answers, verify, Status};
+use advent_of_code::{get_puzzle, latest_year, Puzzle, SolveError, Solver, PUZZLES};
 use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
 
-fn parse_args() -> Result<(String, Solver), String> {
-    let args: Vec<String> = env::args().collect();
-    if args.len() <= 2 {
+const USAGE: &str = "\
+Usage: advent_of_code (day) (part) [input_file | input_dir | -] [--year Y] [--format F] [--timeout S]
+                      [--progress P] [--param NAME=VALUE]... [--history FILE]
+       advent_of_code all [--year Y] [--format F] [--jobs N] [--timeout S] [--progress P] [--history FILE]
+       advent_of_code (first_day)..(last_day) [--year Y] [--format F] [--jobs N] [--timeout S] [--progress P]
+                      [--history FILE]
+       advent_of_code bench (day) (part) [input_file] [--year Y] [--runs N] [--warmup N] [--csv FILE]
+                      [--param NAME=VALUE]...
+       advent_of_code verify [answers_file] [--year Y] [--jobs N] [--timeout S] [--progress P] [--history FILE]
+       advent_of_code generate (day) [--year Y] [--seed N] [--scale N]
+       advent_of_code crosscheck [day [input_file]] [--year Y] [--seeds N]
+       advent_of_code history [--history FILE] [--year Y] [--threshold P]
+       advent_of_code report [--out FILE] [--answers FILE] [--mask] [--year Y] [--jobs N] [--timeout S]
+                      [--history FILE]
+
+The days are the ones of the most recent year, unless another one is selected with --year Y;
+all and verify run every year unless a year is selected.
+The input file defaults to input/(year)/(day), and - reads the standard input.
+A directory runs the part on each input it holds.
+With --format json (instead of text), each run is printed as a JSON object on its own line.
+With --jobs N, the days are solved concurrently on N threads.
+With --timeout S, a part still running after S seconds is reported as timed out.
+With --progress bar or log (instead of none), the long solvers show their progress
+on the standard error.
+With --param NAME=VALUE, a constant of the puzzle such as a number of iterations
+is changed, for instance to solve the examples of the puzzle text.
+The generate command prints a random input of the day, always the same one
+for a seed (0 by default), and bigger than the puzzle inputs with --scale N.
+The crosscheck command compares the optimised solvers with their slow reference
+implementations on the inputs generated from N seeds (20 by default)
+or on the input file, and prints the smallest input they disagree on.
+Every run of a solver on a puzzle input is recorded in the history file, history.csv
+unless another one is given with --history FILE; the runs of bench and crosscheck
+are not, as they repeat a solver or run it on generated inputs. The history command
+shows the trend of the solve time of each part, flagging the last run if it is more than
+P percent (20 by default) slower than the previous one, or if its answer changed.
+The report command runs every solver and writes a Markdown table of the answers
+and timings, or an HTML page if the file ends with .html, comparing the answers
+with the answers file if one is given, and hiding them with --mask.
+
+Exit codes:
+    1  invalid arguments
+    2  the input could not be read
+    3  a solver panicked
+    4  an answer differs from the recorded one
+    5  the input could not be parsed
+    6  the input does not describe a valid puzzle
+    7  the puzzle has no solution for the input
+    8  a solver timed out";
+
+/// Reason why the program stops early
+enum Exit {
+    /// The command line is invalid
+    Usage(String),
+    /// A puzzle could not be solved
+    Solve(SolveError),
+    /// The failure has already been reported, only the exit code is left
+    Code(i32),
+}
+
+impl From<String> for Exit {
+    fn from(reason: String) -> Self {
+        Exit::Usage(reason)
+    }
+}
+
+impl From<SolveError> for Exit {
+    fn from(e: SolveError) -> Self {
+        Exit::Solve(e)
+    }
+}
+
+/// Exit code corresponding to the error
+fn exit_code(e: &SolveError) -> i32 {
+    match *e {
+        SolveError::Io(_) => 2,
+        SolveError::Parse { .. } => 5,
+        SolveError::InvalidInput(_) => 6,
+        SolveError::NoSolution(_) => 7,
+        SolveError::Cancelled => 8,
+    }
+}
+
+/// A part of a puzzle selected on the command line, with its input
+struct Selection {
+    puzzle: &'static Puzzle,
+    part: u32,
+    solver: Solver,
+    filename: String,
+}
+
+fn parse_args(year: u32, args: &[String]) -> Result<Selection, String> {
+    if args.len() < 2 {
         return Err(String::from("not enough arguments"));
     }
 
-    let day: u32 = args[1]
+    let day: u32 = args[0]
         .parse()
-        .or_else(|_| Err(String::from("day must be an integer (1 to 25)")))?;
+        .map_err(|_| String::from("day must be an integer (1 to 25)"))?;
 
-    let part: u32 = args[2]
+    let part: u32 = args[1]
         .parse()
-        .or_else(|_| Err(String::from("part must be an integer (1 or 2)")))?;
+        .map_err(|_| String::from("part must be an integer (1 or 2)"))?;
+
+    let not_implemented = || {
+        format!(
+            "the function for {} day {}, part {} has not been implemented yet",
+            year, day, part
+        )
+    };
+    let puzzle = get_puzzle(year, day).ok_or_else(not_implemented)?;
+    let solver = puzzle.solver(part).ok_or_else(not_implemented)?;
 
-    let filename = if args.len() > 3 {
-        args[3].to_string()
+    let filename = if args.len() > 2 {
+        args[2].to_string()
     } else {
-        format!("input/day{}", day)
+        puzzle.input.to_string()
     };
+    Ok(Selection {
+        puzzle,
+        part,
+        solver,
+        filename,
+    })
+}
 
-    let solver = get_solver(day, part).ok_or_else(|| {
-        format!(
-            "the function for day {}, part {} has not been implemented yet",
-            day, part
-        )
-    })?;
-    Ok((filename, solver))
+/// Removes an option and its value from the arguments,
+/// and parses the value if the option was provided
+fn take_option<T: FromStr>(args: &mut Vec<String>, name: &str) -> Result<Option<T>, String> {
+    let i = match args.iter().position(|a| a == name) {
+        Some(i) => i,
+        None => return Ok(None),
+    };
+    if i + 1 >= args.len() {
+        return Err(format!("missing value for {}", name));
+    }
+    let value = args.remove(i + 1);
+    args.remove(i);
+    value
+        .parse()
+        .map(Some)
+        .map_err(|_| format!("invalid value for {}: {}", name, value))
 }
 
-fn main() {
-    let usage = "Usage: advent_of_code (day) (part) [input_file]";
+/// Output format of the answers
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    /// Answers only, or a table for several days
+    Text,
+    /// One JSON object per line and per run
+    Json,
+}
 
-    let (filename, solver) = match parse_args() {
-        Ok((filename, solver)) => (filename, solver),
-        Err(reason) => {
-            eprintln!("{}\n\n{}", reason, usage);
-            std::process::exit(1)
+impl FromStr for Format {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(()),
         }
+    }
+}
+
+/// Exit code corresponding to a failed run
+fn failure_code(failure: &Failure) -> i32 {
+    match *failure {
+        Failure::Error(ref e) => exit_code(e),
+        Failure::Panic(_) => 3,
+        Failure::Timeout(_) => 8,
+    }
+}
+
+/// Solves a single part of a puzzle and prints the answer.
+/// If the input is a directory, the part is solved for each input it holds,
+/// and each answer is labelled with the name of its input.
+fn run_single(
+    year: u32,
+    args: &[String],
+    format: Format,
+    options: Options,
+    history: &str,
+) -> Result<(), Exit> {
+    let selection = parse_args(year, args)?;
+    param::check(selection.puzzle.params, &options.params)?;
+    let filename = &selection.filename;
+    let is_dir = Path::new(filename).is_dir();
+    let inputs = if is_dir {
+        list_inputs(filename)?
+    } else {
+        vec![(filename.clone(), filename.clone())]
     };
 
-    let input = match get_input(&filename) {
-        Ok(contents) => contents,
-        Err(e) => {
-            eprintln!("{}\n\n{}", e, usage);
-            std::process::exit(2)
+    let mut code = None;
+    let mut runs = Vec::new();
+    for (name, path) in inputs {
+        let run = match run_part(selection.puzzle, selection.part, &path, &options) {
+            Some(run) => run,
+            None => continue,
+        };
+        match (format, &run.answer) {
+            (Format::Json, _) => println!("{}", run.to_json()),
+            (Format::Text, Ok(answer)) if is_dir => println!("{}: {}", name, answer),
+            (Format::Text, Ok(answer)) => println!("{}", answer),
+            (Format::Text, Err(failure)) if is_dir => eprintln!("{}: {}", name, failure),
+            (Format::Text, Err(failure)) => eprintln!("{}", failure),
+        }
+        if let Err(ref failure) = run.answer {
+            code = code.or_else(|| Some(failure_code(failure)));
         }
+        runs.push(run);
+    }
+    record_history(history, &runs);
+    if let Some(code) = code {
+        Err(Exit::Code(code))?
+    }
+    Ok(())
+}
+
+/// Benchmarks a solver with repeated runs and prints the statistics
+fn run_bench(mut args: Vec<String>) -> Result<(), Exit> {
+    let runs = take_option(&mut args, "--runs")?.unwrap_or(10);
+    let warmup = take_option(&mut args, "--warmup")?.unwrap_or(1);
+    let csv: Option<String> = take_option(&mut args, "--csv")?;
+    let params = take_params(&mut args)?;
+    let year = take_year(&mut args)?.unwrap_or_else(latest_year);
+    check_positional(&args, 3)?;
+    if runs == 0 {
+        Err(String::from("the number of runs must be positive"))?
+    }
+    let selection = parse_args(year, &args)?;
+    param::check(selection.puzzle.params, &params)?;
+    param::set_current(params);
+    let input = read_input(&selection.filename)?;
+
+    let (answer, stats) = bench(selection.solver, &input, warmup, runs)?;
+    println!("{}", answer);
+    println!(
+        "{} runs: min {}, median {}, mean {}, stddev {}",
+        stats.runs,
+        format_duration(stats.min),
+        format_duration(stats.median),
+        format_duration(stats.mean),
+        format_duration(stats.stddev)
+    );
+
+    if let Some(csv) = csv {
+        let puzzle = selection.puzzle;
+        append_csv(&csv, puzzle.year, puzzle.day, selection.part, &stats)
+            .map_err(|e| format!("cannot write {}: {}", csv, e))?;
+    }
+    Ok(())
+}
+
+/// Checks that only positional arguments are left, at most `max` of them,
+/// every option having been taken
+fn check_positional(args: &[String], max: usize) -> Result<(), String> {
+    if let Some(option) = args.iter().find(|a| a.starts_with("--")) {
+        return Err(format!("unknown option: {}", option));
+    }
+    match args.get(max) {
+        Some(arg) => Err(format!("unexpected argument: {}", arg)),
+        None => Ok(()),
+    }
+}
+
+/// Removes an option without value from the arguments,
+/// returning whether it was provided
+fn take_flag(args: &mut Vec<String>, name: &str) -> bool {
+    match args.iter().position(|a| a == name) {
+        Some(i) => {
+            args.remove(i);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Takes the year of the puzzles, if one is selected
+fn take_year(args: &mut Vec<String>) -> Result<Option<u32>, String> {
+    take_option(args, "--year")
+}
+
+/// Whether the puzzle belongs to the selected year, if any
+fn in_year(puzzle: &Puzzle, year: Option<u32>) -> bool {
+    year.is_none_or(|year| puzzle.year == year)
+}
+
+/// Finds the puzzle of a day of the year
+fn parse_day(year: u32, arg: &str) -> Result<&'static Puzzle, String> {
+    let day: u32 = arg
+        .parse()
+        .map_err(|_| String::from("day must be an integer (1 to 25)"))?;
+    get_puzzle(year, day).ok_or_else(|| format!("{} day {} has not been implemented yet", year, day))
+}
+
+/// Takes the number of threads to run the solvers on, if one is given
+fn take_jobs(args: &mut Vec<String>) -> Result<Option<usize>, String> {
+    match take_option(args, "--jobs")? {
+        Some(0) => Err(String::from("the number of jobs must be positive")),
+        jobs => Ok(jobs),
+    }
+}
+
+/// Takes the settings of the runs: the time after which a solver is stopped,
+/// in seconds, and how the progress of the solvers is shown
+fn take_run_options(args: &mut Vec<String>) -> Result<Options, String> {
+    let timeout = match take_option::<f64>(args, "--timeout")? {
+        Some(secs) => Duration::try_from_secs_f64(secs)
+            .ok()
+            .filter(|d| *d > Duration::default())
+            .map(Some)
+            .ok_or_else(|| String::from("the timeout must be a positive number of seconds"))?,
+        None => None,
     };
+    let progress = take_option(args, "--progress")?.unwrap_or_default();
+    Ok(Options {
+        timeout,
+        progress,
+        params: param::Values::new(),
+    })
+}
+
+/// Takes the values given to the parameters of the puzzle,
+/// with one `--param name=value` option per parameter
+fn take_params(args: &mut Vec<String>) -> Result<param::Values, String> {
+    let mut values = param::Values::new();
+    while let Some(assignment) = take_option::<String>(args, "--param")? {
+        let (name, value) = parse_assignment(&assignment)
+            .map_err(|e| format!("invalid value for --param: {}", e))?;
+        values.insert(name, value);
+    }
+    Ok(values)
+}
+
+/// Runs every solver and compares the outputs with the answers file
+fn run_verify(mut args: Vec<String>) -> Result<(), Exit> {
+    let jobs = take_jobs(&mut args)?.unwrap_or(1);
+    let options = take_run_options(&mut args)?;
+    let year = take_year(&mut args)?;
+    let history = take_history(&mut args)?;
+    check_positional(&args, 1)?;
+    let filename = args.first().map(|s| s.as_str()).unwrap_or("input/answers");
+    let answers = read_input(filename)?;
+    let mut answers = parse_answers(&answers).map_err(|e| format!("{}: {}", filename, e))?;
+    answers.retain(|&(y, _, _), _| year.is_none_or(|year| y == year));
+
+    let puzzles = PUZZLES.iter().cloned().filter(|p| in_year(p, year));
+    let runs = run_parallel(puzzles, jobs, &options);
+    record_history(&history, &runs);
+    let verdicts = verify(&runs, &answers);
+    for v in &verdicts {
+        println!("{}", v);
+    }
+    if verdicts.iter().any(|v| v.status == Status::Fail || v.status == Status::Format) {
+        Err(Exit::Code(4))?
+    }
+    Ok(())
+}
+
+/// Prints a random input of a day
+fn run_generate(mut args: Vec<String>) -> Result<(), Exit> {
+    let seed = take_option(&mut args, "--seed")?.unwrap_or(0);
+    let scale = match take_option(&mut args, "--scale")? {
+        Some(0) => Err(String::from("the scale must be positive"))?,
+        scale => scale.unwrap_or(1),
+    };
+    let year = take_year(&mut args)?.unwrap_or_else(latest_year);
+    check_positional(&args, 1)?;
+    let day = args.first().ok_or_else(|| String::from("not enough arguments"))?;
+    let puzzle = parse_day(year, day)?;
+    println!("{}", generate(puzzle, seed, scale));
+    Ok(())
+}
+
+/// Cross-checks the optimised solvers with their reference implementations
+fn run_crosscheck(mut args: Vec<String>) -> Result<(), Exit> {
+    let seeds = match take_option(&mut args, "--seeds")? {
+        Some(0) => Err(String::from("the number of seeds must be positive"))?,
+        seeds => seeds.unwrap_or(20),
+    };
+    let year = take_year(&mut args)?;
+    check_positional(&args, 2)?;
+    let puzzles: Vec<&Puzzle> = match args.first() {
+        Some(day) => {
+            let puzzle = parse_day(year.unwrap_or_else(latest_year), day)?;
+            if puzzle.checks.is_empty() {
+                Err(format!(
+                    "{} day {} has no reference implementation",
+                    puzzle.year, puzzle.day
+                ))?
+            }
+            vec![puzzle]
+        }
+        None => PUZZLES.iter().cloned().filter(|p| in_year(p, year)).collect(),
+    };
+    let input = match args.get(1) {
+        Some(filename) => Some(read_input(filename)?),
+        None => None,
+    };
+
+    let mut failed = false;
+    for puzzle in puzzles {
+        let mismatches = cross_check(puzzle, 0..seeds, input.as_ref().map(|i| i.as_str()));
+        for check in puzzle.checks {
+            match mismatches.iter().find(|m| m.part == check.part) {
+                Some(mismatch) => println!("FAIL {}", mismatch),
+                None => println!("PASS {} day {} part {}", puzzle.year, puzzle.day, check.part),
+            }
+        }
+        failed |= !mismatches.is_empty();
+    }
+    if failed {
+        Err(Exit::Code(4))?
+    }
+    Ok(())
+}
+
+/// Takes the file the runs are recorded in, `history.csv` by default
+fn take_history(args: &mut Vec<String>) -> Result<String, String> {
+    let file = take_option(args, "--history")?;
+    Ok(file.unwrap_or_else(|| history::DEFAULT_FILE.to_string()))
+}
+
+/// Appends the runs to the history file,
+/// only warning if it cannot be written so that the answers are still printed
+fn record_history(filename: &str, runs: &[Run]) {
+    let revision = current_revision();
+    if let Err(e) = history::append(filename, runs, revision.as_deref()) {
+        eprintln!("cannot record the runs in {}: {}", filename, e);
+    }
+}
+
+/// Shows the timing trend of every part in the history file,
+/// flagging the parts whose last run became slower or changed its answer
+fn run_history(mut args: Vec<String>) -> Result<(), Exit> {
+    let threshold = match take_option::<f64>(&mut args, "--threshold")? {
+        Some(p) if p.is_nan() || p < 0. => {
+            Err(String::from("the threshold must be a positive percentage"))?
+        }
+        threshold => threshold.unwrap_or(20.),
+    };
+    let year = take_year(&mut args)?;
+    let filename = take_history(&mut args)?;
+    check_positional(&args, 0)?;
+    let mut records = history::read(&filename).map_err(|e| {
+        let e = io::Error::new(e.kind(), format!("cannot read {}: {}", filename, e));
+        SolveError::Io(e)
+    })?;
+    records.retain(|r| year.is_none_or(|year| r.year == year));
+
+    let trends = history::trends(&records, threshold);
+    for trend in &trends {
+        println!("{}", trend);
+    }
+    if trends.iter().any(|t| !t.flags.is_empty()) {
+        Err(Exit::Code(4))?
+    }
+    Ok(())
+}
+
+/// Runs every solver and writes a report of the runs,
+/// on the standard output if no file is given
+fn run_report(mut args: Vec<String>) -> Result<(), Exit> {
+    let jobs = take_jobs(&mut args)?.unwrap_or(1);
+    let options = take_run_options(&mut args)?;
+    let year = take_year(&mut args)?;
+    let out: Option<String> = take_option(&mut args, "--out")?;
+    let answers = match take_option::<String>(&mut args, "--answers")? {
+        Some(filename) => {
+            let answers = read_input(&filename)?;
+            Some(parse_answers(&answers).map_err(|e| format!("{}: {}", filename, e))?)
+        }
+        None => None,
+    };
+    let mask = take_flag(&mut args, "--mask");
+    let history = take_history(&mut args)?;
+    check_positional(&args, 0)?;
+
+    let puzzles = PUZZLES.iter().cloned().filter(|p| in_year(p, year));
+    let runs = run_parallel(puzzles, jobs, &options);
+    record_history(&history, &runs);
+    let revision = current_revision();
+    let settings = Settings {
+        answers: answers.as_ref(),
+        mask,
+        revision: revision.as_deref(),
+    };
+    match out {
+        Some(out) => {
+            let report = render(&runs, Document::from_path(&out), &settings);
+            fs::write(&out, report).map_err(|e| format!("cannot write {}: {}", out, e))?
+        }
+        None => print!("{}", render(&runs, Document::Markdown, &settings)),
+    }
+    Ok(())
+}
+
+/// Parses a range of days such as `7..14` (both ends included),
+/// or `all` for every day, rejecting the days beyond 1 to 25 and the reversed ranges
+fn parse_days(arg: &str) -> Option<Result<(u32, u32), String>> {
+    if arg == "all" {
+        return Some(Ok((1, 25)));
+    }
+    let (first, last) = arg.split_once("..")?;
+    let range = first
+        .parse()
+        .and_then(|first| Ok((first, last.trim_start_matches('=').parse()?)))
+        .map_err(|_| format!("invalid range of days: {}", arg))
+        .and_then(|(first, last)| match (first, last) {
+            (1..=25, 1..=25) if first <= last => Ok((first, last)),
+            (1..=25, 1..=25) => Err(format!("empty range of days: {}", arg)),
+            _ => Err(format!("invalid range of days: {} (1 to 25)", arg)),
+        });
+    Some(range)
+}
+
+/// Runs every part of the selected days of the selected year,
+/// or of every year, and prints the results.
+/// Fails with the exit code of the first failed run.
+fn run_days(
+    year: Option<u32>,
+    first: u32,
+    last: u32,
+    format: Format,
+    jobs: usize,
+    options: Options,
+    history: &str,
+) -> Result<(), Exit> {
+    let puzzles = PUZZLES
+        .iter()
+        .cloned()
+        .filter(|p| in_year(p, year) && first <= p.day && p.day <= last);
+    let runs = run_parallel(puzzles, jobs, &options);
+    record_history(history, &runs);
+    match format {
+        Format::Text => print!("{}", format_table(&runs)),
+        Format::Json => {
+            for r in &runs {
+                println!("{}", r.to_json());
+            }
+        }
+    }
 
-    println!("{}", solver(&input));
+    let code = runs.iter().find_map(|r| r.answer.as_ref().err().map(failure_code));
+    if let Some(code) = code {
+        Err(Exit::Code(code))?
+    }
+    Ok(())
+}
+
+/// Solves a single part or a range of days, in the requested format
+fn run_solve(mut args: Vec<String>) -> Result<(), Exit> {
+    let format = take_option(&mut args, "--format")?.unwrap_or(Format::Text);
+    let mut options = take_run_options(&mut args)?;
+    options.params = take_params(&mut args)?;
+    let year = take_year(&mut args)?;
+    let jobs = take_jobs(&mut args)?;
+    let history = take_history(&mut args)?;
+    match args.first().map(|a| parse_days(a)) {
+        Some(Some(range)) => {
+            let (first, last) = range?;
+            check_positional(&args, 1)?;
+            if !options.params.is_empty() {
+                Err(String::from("--param only applies to a single day"))?
+            }
+            // `all` covers every year, while a range of days belongs to a single year
+            let year = match args[0].as_str() {
+                "all" => year,
+                _ => Some(year.unwrap_or_else(latest_year)),
+            };
+            run_days(year, first, last, format, jobs.unwrap_or(1), options, &history)
+        }
+        _ => {
+            check_positional(&args, 3)?;
+            if jobs.is_some() {
+                Err(String::from("--jobs only applies to all and ranges of days"))?
+            }
+            run_single(year.unwrap_or_else(latest_year), &args, format, options, &history)
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match args.first().map(|a| a.as_str()) {
+        Some("bench") => run_bench(args[1..].to_vec()),
+        Some("verify") => run_verify(args[1..].to_vec()),
+        Some("generate") => run_generate(args[1..].to_vec()),
+        Some("crosscheck") => run_crosscheck(args[1..].to_vec()),
+        Some("history") => run_history(args[1..].to_vec()),
+        Some("report") => run_report(args[1..].to_vec()),
+        _ => run_solve(args),
+    };
+
+    match result {
+        Ok(()) => {}
+        Err(Exit::Usage(reason)) => {
+            eprintln!("{}\n\n{}", reason, USAGE);
+            std::process::exit(1)
+        }
+        Err(Exit::Solve(e)) => {
+            eprintln!("{}", e);
+            std::process::exit(exit_code(&e))
+        }
+        Err(Exit::Code(code)) => std::process::exit(code),
+    }
 }
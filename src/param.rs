@@ -0,0 +1,122 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fmt;
+use SolveError;
+
+/// Constant of a puzzle which can be changed from the command line,
+/// for instance to run the smaller examples of the puzzle text
+pub struct Param {
+    /// Name of the parameter, as given to `--param`
+    pub name: &'static str,
+    /// What the parameter stands for
+    pub description: &'static str,
+    /// Value of the parameter for each part,
+    /// or for both parts if there is a single value
+    pub defaults: &'static [u64],
+}
+
+impl Param {
+    /// Default value of the parameter for a part, numbered from 1
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::y2017::day21::ITERATIONS;
+    /// assert_eq!(5, ITERATIONS.default(1));
+    /// assert_eq!(18, ITERATIONS.default(2));
+    /// ```
+    pub fn default(&self, part: u32) -> u64 {
+        let i = (part.max(1) as usize - 1).min(self.defaults.len() - 1);
+        self.defaults[i]
+    }
+}
+
+impl fmt::Display for Param {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {} (", self.name, self.description)?;
+        match *self.defaults {
+            [value] => write!(f, "{}", value)?,
+            _ => {
+                for (i, value) in self.defaults.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{} for part {}", value, i + 1)?;
+                }
+            }
+        }
+        write!(f, " by default)")
+    }
+}
+
+/// Values given to the parameters, by name
+pub type Values = BTreeMap<String, u64>;
+
+/// Parses the value given to a parameter, formatted as `name=value`
+///
+/// # Examples
+/// ```
+/// use advent_of_code::param::parse_assignment;
+/// assert_eq!(Ok((String::from("pairs"), 5)), parse_assignment("pairs=5"));
+/// assert!(parse_assignment("pairs").is_err());
+/// assert!(parse_assignment("pairs=-5").is_err());
+/// ```
+pub fn parse_assignment(s: &str) -> Result<(String, u64), String> {
+    let mut tokens = s.splitn(2, '=');
+    let name = tokens.next().unwrap_or("").trim();
+    let value = tokens
+        .next()
+        .ok_or_else(|| format!("Expected name=value, not {}", s))?
+        .trim();
+    let value = value
+        .parse()
+        .map_err(|e| format!("Could not parse the value of {} as a positive int: {}", name, e))?;
+    Ok((name.to_string(), value))
+}
+
+/// Checks that every value is given to a parameter declared by the puzzle
+pub fn check(params: &[Param], values: &Values) -> Result<(), String> {
+    for name in values.keys() {
+        if !params.iter().any(|p| p.name == name) {
+            let names: Vec<&str> = params.iter().map(|p| p.name).collect();
+            return Err(match names.len() {
+                0 => format!("unknown parameter {}, the puzzle has none", name),
+                _ => format!("unknown parameter {}, expected {}", name, names.join(" or ")),
+            });
+        }
+    }
+    Ok(())
+}
+
+thread_local! {
+    /// Values of the parameters for the solver running on the thread
+    static CURRENT: RefCell<Values> = const { RefCell::new(BTreeMap::new()) };
+}
+
+/// Sets the values of the parameters for the solvers
+/// running on the current thread
+pub fn set_current(values: Values) {
+    CURRENT.with(|current| *current.borrow_mut() = values);
+}
+
+/// Value of the parameter for a part of the solver running on the current thread,
+/// converted to the type used by the solver.
+/// Without a value for it, the default value of the part is used.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day21::ITERATIONS;
+/// use advent_of_code::param;
+/// assert_eq!(18, param::get::<usize>(&ITERATIONS, 2).unwrap());
+/// param::set_current(vec![(String::from("iterations"), 300)].into_iter().collect());
+/// assert_eq!(300, param::get::<usize>(&ITERATIONS, 2).unwrap());
+/// assert!(param::get::<u8>(&ITERATIONS, 2).is_err());
+/// ```
+pub fn get<T: TryFrom<u64>>(param: &Param, part: u32) -> Result<T, SolveError> {
+    let value = CURRENT
+        .with(|current| current.borrow().get(param.name).cloned())
+        .unwrap_or_else(|| param.default(part));
+    T::try_from(value).map_err(|_| {
+        SolveError::InvalidInput(format!("{} is too large for {}", value, param.name))
+    })
+}
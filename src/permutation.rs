@@ -0,0 +1,208 @@
+use SolveError;
+
+/// Permutation of the numbers from 0, mapping each number to its image.
+/// Applied to a list, it moves the item at each position to the image of the position.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Permutation {
+    images: Vec<usize>,
+}
+
+impl Permutation {
+    /// Creates the permutation mapping each number to the image at its index,
+    /// checking that every number has a single antecedent
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::permutation::Permutation;
+    /// assert_eq!(2, Permutation::new(vec![2, 0, 1]).unwrap().apply(0));
+    /// assert!(Permutation::new(vec![2, 0, 2]).is_err());
+    /// assert!(Permutation::new(vec![3, 0, 1]).is_err());
+    /// ```
+    pub fn new(images: Vec<usize>) -> Result<Self, SolveError> {
+        let mut reached = vec![false; images.len()];
+        for &i in &images {
+            match reached.get_mut(i) {
+                Some(r) if !*r => *r = true,
+                _ => {
+                    return Err(SolveError::InvalidInput(format!(
+                        "{:?} is not a permutation",
+                        images
+                    )))
+                }
+            }
+        }
+        Ok(Permutation { images })
+    }
+
+    /// Permutation of `n` numbers leaving them in place
+    pub fn identity(n: usize) -> Self {
+        Permutation {
+            images: (0..n).collect(),
+        }
+    }
+
+    /// Permutation of `n` numbers shifting each one `k` places forwards,
+    /// the last ones wrapping around to the front
+    pub fn rotation(n: usize, k: usize) -> Self {
+        Permutation {
+            images: (0..n).map(|i| (i + k) % n).collect(),
+        }
+    }
+
+    /// Permutation of `n` numbers exchanging `a` and `b`
+    pub fn transposition(n: usize, a: usize, b: usize) -> Self {
+        let mut p = Permutation::identity(n);
+        p.images.swap(a, b);
+        p
+    }
+
+    /// Number of permuted numbers
+    pub fn len(&self) -> usize {
+        self.images.len()
+    }
+
+    /// Whether the permutation permutes no number
+    pub fn is_empty(&self) -> bool {
+        self.images.is_empty()
+    }
+
+    /// Image of the number
+    pub fn apply(&self, i: usize) -> usize {
+        self.images[i]
+    }
+
+    /// Image of each number, in order
+    pub fn images(&self) -> &[usize] {
+        &self.images
+    }
+
+    /// Composition applying this permutation, then the other one
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::permutation::Permutation;
+    /// let spin = Permutation::rotation(5, 1);
+    /// let exchange = Permutation::transposition(5, 3, 4);
+    /// assert_eq!(&[1, 2, 4, 3, 0], spin.then(&exchange).images());
+    /// assert_eq!(&[1, 2, 3, 0, 4], exchange.then(&spin).images());
+    /// ```
+    pub fn then(&self, other: &Permutation) -> Self {
+        Permutation {
+            images: self.images.iter().map(|&i| other.images[i]).collect(),
+        }
+    }
+
+    /// Permutation bringing every number back to its antecedent
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::permutation::Permutation;
+    /// let p = Permutation::new(vec![2, 0, 3, 1]).unwrap();
+    /// assert_eq!(&[1, 3, 0, 2], p.inverse().images());
+    /// assert_eq!(Permutation::identity(4), p.then(&p.inverse()));
+    /// ```
+    pub fn inverse(&self) -> Self {
+        let mut images = vec![0; self.len()];
+        for (i, &image) in self.images.iter().enumerate() {
+            images[image] = i;
+        }
+        Permutation { images }
+    }
+
+    /// Permutation applied `n` times in a row, by exponentiation by squaring
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::permutation::Permutation;
+    /// let exchange = Permutation::transposition(16, 5, 12);
+    /// assert_eq!(exchange, exchange.pow(1));
+    /// assert_eq!(Permutation::identity(16), exchange.pow(2));
+    /// let spin = Permutation::rotation(16, 3);
+    /// assert_eq!(Permutation::rotation(16, 3_000_000_000 % 16), spin.pow(1_000_000_000));
+    /// ```
+    pub fn pow(&self, mut n: u64) -> Self {
+        let mut power = Permutation::identity(self.len());
+        let mut square = self.clone();
+        while n > 0 {
+            if n & 1 > 0 {
+                power = power.then(&square);
+            }
+            square = square.then(&square);
+            n >>= 1;
+        }
+        power
+    }
+
+    /// Cycles of the permutation, each starting with its smallest number
+    /// followed by its successive images, ordered by their smallest number.
+    /// The numbers left in place form cycles of a single number.
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::permutation::Permutation;
+    /// let p = Permutation::new(vec![3, 1, 0, 4, 2]).unwrap();
+    /// assert_eq!(vec![vec![0, 3, 4, 2], vec![1]], p.cycles());
+    /// ```
+    pub fn cycles(&self) -> Vec<Vec<usize>> {
+        let mut seen = vec![false; self.len()];
+        let mut cycles = Vec::new();
+        for start in 0..self.len() {
+            if seen[start] {
+                continue;
+            }
+            let mut cycle = vec![start];
+            seen[start] = true;
+            let mut i = self.images[start];
+            while i != start {
+                cycle.push(i);
+                seen[i] = true;
+                i = self.images[i];
+            }
+            cycles.push(cycle);
+        }
+        cycles
+    }
+
+    /// Smallest positive number of times the permutation must be applied
+    /// to get back to the identity: the least common multiple of the lengths of its cycles
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::permutation::Permutation;
+    /// let p = Permutation::new(vec![1, 2, 0, 4, 3]).unwrap();
+    /// assert_eq!(6, p.order());
+    /// assert_eq!(Permutation::identity(5), p.pow(6));
+    /// assert_eq!(1, Permutation::identity(5).order());
+    /// ```
+    pub fn order(&self) -> u64 {
+        self.cycles().iter().fold(1, |order, cycle| {
+            let length = cycle.len() as u64;
+            order / gcd(order, length) * length
+        })
+    }
+
+    /// Moves each item to the image of its position
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::permutation::Permutation;
+    /// let spin = Permutation::rotation(5, 1);
+    /// assert_eq!(vec!['e', 'a', 'b', 'c', 'd'], spin.permute(&['a', 'b', 'c', 'd', 'e']));
+    /// ```
+    pub fn permute<T: Clone>(&self, items: &[T]) -> Vec<T> {
+        self.inverse()
+            .images
+            .iter()
+            .map(|&i| items[i].clone())
+            .collect()
+    }
+}
+
+/// Greatest common divisor
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
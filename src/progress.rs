@@ -0,0 +1,215 @@
+//! Progress of the long-running solvers, shown as a bar or as log lines.
+//!
+//! The runner installs a reporter on the thread of each solver with `set_current`,
+//! and the solvers call `report` from their main loop. The reporter is ambient
+//! thread-local state, like the cancellation token of `cancel` and the values of
+//! `param`, rather than an argument: the parts keep the signatures of `Day` and
+//! `Solver` shared by every day, and the many which never report need no change.
+//! A thread runs a single solver at a time, and the runner clears the reporter
+//! once the solver returns, so that it never leaks into another run.
+
+use std::cell::RefCell;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Receives the progress of a long-running solver
+pub trait Progress: Send + Sync {
+    /// Reports that `done` steps have been done,
+    /// out of `total` if the number of steps is known
+    fn update(&self, done: u64, total: Option<u64>);
+
+    /// Reports that the solver is over
+    fn finish(&self) {}
+}
+
+/// Ignores the progress, for tests and library use
+pub struct Silent;
+
+impl Progress for Silent {
+    fn update(&self, _done: u64, _total: Option<u64>) {}
+}
+
+/// Formats the progress, as a percentage if the total is known
+fn describe(done: u64, total: Option<u64>) -> String {
+    match total {
+        Some(total) if total > 0 => format!("{}/{} ({}%)", done, total, done * 100 / total),
+        _ => format!("{} steps", done),
+    }
+}
+
+/// Whether enough time has passed since the last output,
+/// in which case the time of the output is recorded
+fn due(last: &Mutex<Option<Instant>>, interval: Duration) -> bool {
+    let mut last = last.lock().unwrap_or_else(|e| e.into_inner());
+    match *last {
+        Some(t) if t.elapsed() < interval => false,
+        _ => {
+            *last = Some(Instant::now());
+            true
+        }
+    }
+}
+
+/// Draws a progress bar on a single line of the standard error
+pub struct Bar {
+    label: String,
+    last: Mutex<Option<Instant>>,
+}
+
+impl Bar {
+    /// Width of the bar, in characters
+    const WIDTH: u64 = 30;
+    /// Minimal time between two redraws of the bar
+    const REFRESH: Duration = Duration::from_millis(100);
+
+    /// Creates a progress bar preceded by the label
+    pub fn new(label: &str) -> Self {
+        Bar {
+            label: label.to_string(),
+            last: Mutex::new(None),
+        }
+    }
+}
+
+impl Progress for Bar {
+    fn update(&self, done: u64, total: Option<u64>) {
+        if !due(&self.last, Self::REFRESH) {
+            return;
+        }
+        let bar = match total {
+            Some(total) if total > 0 => {
+                let filled = (done.min(total) * Self::WIDTH / total) as usize;
+                format!(
+                    "[{}{}] ",
+                    "#".repeat(filled),
+                    " ".repeat(Self::WIDTH as usize - filled)
+                )
+            }
+            _ => String::new(),
+        };
+        // Progress is not worth failing the solver
+        let _ = write!(io::stderr(), "\r{} {}{}", self.label, bar, describe(done, total));
+    }
+
+    fn finish(&self) {
+        if self.last.lock().map(|last| last.is_some()).unwrap_or(true) {
+            let _ = write!(io::stderr(), "\r\x1b[K");
+        }
+    }
+}
+
+/// Writes the progress on the standard error,
+/// one line at most every `interval`
+pub struct Log {
+    label: String,
+    interval: Duration,
+    last: Mutex<Option<Instant>>,
+}
+
+impl Log {
+    /// Creates a log whose lines start with the label
+    pub fn new(label: &str, interval: Duration) -> Self {
+        Log {
+            label: label.to_string(),
+            interval,
+            last: Mutex::new(None),
+        }
+    }
+}
+
+impl Progress for Log {
+    fn update(&self, done: u64, total: Option<u64>) {
+        if due(&self.last, self.interval) {
+            eprintln!("{}: {}", self.label, describe(done, total));
+        }
+    }
+}
+
+/// How the progress of the solvers is shown
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Style {
+    /// Not at all
+    #[default]
+    Silent,
+    /// With a progress bar
+    Bar,
+    /// With a line every second
+    Log,
+}
+
+impl Style {
+    /// Creates a reporter of this style, labelled with the name of the solver
+    pub fn reporter(self, label: &str) -> Arc<dyn Progress> {
+        match self {
+            Style::Silent => Arc::new(Silent),
+            Style::Bar => Arc::new(Bar::new(label)),
+            Style::Log => Arc::new(Log::new(label, Duration::from_secs(1))),
+        }
+    }
+}
+
+impl FromStr for Style {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Style::Silent),
+            "bar" => Ok(Style::Bar),
+            "log" => Ok(Style::Log),
+            _ => Err(()),
+        }
+    }
+}
+
+thread_local! {
+    /// Reporter of the solver running on the thread, if any
+    static CURRENT: RefCell<Option<Arc<dyn Progress>>> = const { RefCell::new(None) };
+}
+
+/// Sets the reporter receiving the progress of the solvers
+/// running on the current thread
+pub fn set_current(progress: Option<Arc<dyn Progress>>) {
+    CURRENT.with(|current| *current.borrow_mut() = progress);
+}
+
+/// Reports the progress of the solver running on the current thread.
+/// Without a reporter, the progress is ignored.
+///
+/// # Examples
+/// ```
+/// use std::sync::{Arc, Mutex};
+/// use advent_of_code::progress::{self, Progress};
+///
+/// struct Record(Mutex<Vec<u64>>);
+///
+/// impl Progress for Record {
+///     fn update(&self, done: u64, _total: Option<u64>) {
+///         self.0.lock().unwrap().push(done);
+///     }
+/// }
+///
+/// progress::report(1, Some(3));
+/// let record = Arc::new(Record(Mutex::new(vec![])));
+/// progress::set_current(Some(record.clone()));
+/// progress::report(2, Some(3));
+/// progress::report(3, Some(3));
+/// assert_eq!(vec![2, 3], *record.0.lock().unwrap());
+/// ```
+pub fn report(done: u64, total: Option<u64>) {
+    CURRENT.with(|current| {
+        if let Some(ref progress) = *current.borrow() {
+            progress.update(done, total);
+        }
+    });
+}
+
+/// Reports that the solver running on the current thread is over
+pub fn finish() {
+    CURRENT.with(|current| {
+        if let Some(ref progress) = *current.borrow() {
+            progress.finish();
+        }
+    });
+}
@@ -0,0 +1,151 @@
+use std::any::Any;
+use std::sync::Arc;
+use crosscheck::Check;
+use generate::Generator;
+use param::Param;
+use {Answer, SolveError, Solver};
+
+/// A puzzle whose input is parsed once,
+/// then shared by the resolution of both of its parts
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day13::Day13;
+/// use advent_of_code::puzzle::Day;
+/// let scanners = Day13::parse("0: 3\n1: 2\n4: 4\n6: 4").unwrap();
+/// assert_eq!(4, scanners.len());
+/// assert_eq!("24", Day13::part1(&scanners).unwrap());
+/// assert_eq!("10", Day13::part2(&scanners).unwrap());
+/// ```
+pub trait Day {
+    /// Representation of the puzzle input once parsed,
+    /// which can be shared with the thread solving a part
+    type Input: Send + Sync + 'static;
+
+    /// Checks the structure of the normalized input before it is parsed,
+    /// for the days whose parser would otherwise misread a malformed input
+    fn validate(_s: &str) -> Result<(), SolveError> {
+        Ok(())
+    }
+
+    /// Parses the puzzle input
+    fn parse(s: &str) -> Result<Self::Input, SolveError>;
+
+    /// Solves the first part of the puzzle
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError>;
+
+    /// Solves the second part of the puzzle,
+    /// for the days which have one
+    fn part2(_input: &Self::Input) -> Result<Answer, SolveError> {
+        Err(SolveError::NoSolution(String::from(
+            "This puzzle has no second part",
+        )))
+    }
+}
+
+/// Parsed input of any day
+pub type Parsed = Arc<dyn Any + Send + Sync>;
+
+/// Validates and parses the input of the day,
+/// the way every solver of the day should read it
+///
+/// # Examples
+/// ```
+/// use advent_of_code::puzzle::read;
+/// use advent_of_code::y2017::day1::Day1;
+/// assert_eq!(vec![1, 2, 3], read::<Day1>("123").unwrap());
+/// assert!(read::<Day1>("12\n3").is_err());
+/// ```
+pub fn read<D: Day>(s: &str) -> Result<D::Input, SolveError> {
+    D::validate(s)?;
+    D::parse(s)
+}
+
+/// Validates and parses the input of the day, hiding its type
+pub fn parse<D: Day>(s: &str) -> Result<Parsed, SolveError> {
+    read::<D>(s).map(|input| Arc::new(input) as Parsed)
+}
+
+/// Solves a part from the input parsed by `parse::<D>`,
+/// failing for any part other than 1 and 2
+///
+/// # Examples
+/// ```
+/// use advent_of_code::puzzle::{parse, solve};
+/// use advent_of_code::y2017::day13::Day13;
+/// let input = parse::<Day13>("0: 3\n1: 2\n4: 4\n6: 4").unwrap();
+/// assert_eq!("10", solve::<Day13>(&input, 2).unwrap());
+/// assert!(solve::<Day13>(&input, 0).is_err());
+/// assert!(solve::<Day13>(&input, 3).is_err());
+/// ```
+///
+/// # Panics
+/// Panics if the input has been parsed for another day.
+pub fn solve<D: Day>(input: &Parsed, part: u32) -> Result<Answer, SolveError> {
+    let input = input
+        .downcast_ref::<D::Input>()
+        .expect("The input has been parsed for another day");
+    match part {
+        1 => D::part1(input),
+        2 => D::part2(input),
+        _ => Err(SolveError::NoSolution(format!("The puzzle has no part {}", part))),
+    }
+}
+
+/// Describes a puzzle of the calendar,
+/// and the solvers implemented for each of its parts
+pub struct Puzzle {
+    /// Year of the event the puzzle belongs to
+    pub year: u32,
+    /// Day of the puzzle, from 1 to 25
+    pub day: u32,
+    /// Title of the puzzle, as published
+    pub title: &'static str,
+    /// Short summary of what is being solved
+    pub description: &'static str,
+    /// Input file used when none is provided
+    pub input: &'static str,
+    /// Solvers of the parts, in order
+    pub parts: &'static [Solver],
+    /// Constants of the puzzle which can be changed from the command line
+    pub params: &'static [Param],
+    /// Generates random inputs of the puzzle
+    pub generate: Generator,
+    /// Reference implementations the solvers are cross-checked with
+    pub checks: &'static [Check],
+    /// Validates and parses the input once for all the parts
+    pub parse: fn(&str) -> Result<Parsed, SolveError>,
+    /// Solves a part from the parsed input
+    pub solve: fn(&Parsed, u32) -> Result<Answer, SolveError>,
+}
+
+impl Puzzle {
+    /// Get the solver of a part, numbered from 1
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::get_puzzle;
+    /// let puzzle = get_puzzle(2017, 25).unwrap();
+    /// assert!(puzzle.solver(1).is_some());
+    /// assert!(puzzle.solver(2).is_none());
+    /// assert!(puzzle.solver(0).is_none());
+    /// ```
+    pub fn solver(&self, part: u32) -> Option<Solver> {
+        if part == 0 {
+            return None;
+        }
+        self.parts.get(part as usize - 1).cloned()
+    }
+
+    /// Iterates over the numbers of the implemented parts
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::get_puzzle;
+    /// let parts: Vec<u32> = get_puzzle(2017, 7).unwrap().part_numbers().collect();
+    /// assert_eq!(vec![1, 2], parts);
+    /// ```
+    pub fn part_numbers(&self) -> impl Iterator<Item = u32> {
+        1..self.parts.len() as u32 + 1
+    }
+}
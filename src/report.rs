@@ -0,0 +1,197 @@
+use std::path::Path;
+use std::time::Duration;
+use get_puzzle;
+use runner::{format_duration, Run};
+use verify::{self, Answers, Status};
+
+/// Format of a report
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Document {
+    Markdown,
+    Html,
+}
+
+impl Document {
+    /// Format of a report written to the file, guessed from its extension:
+    /// HTML for `.html` and `.htm` files, Markdown otherwise
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::report::Document;
+    /// assert_eq!(Document::Html, Document::from_path("status/report.html"));
+    /// assert_eq!(Document::Markdown, Document::from_path("report.md"));
+    /// ```
+    pub fn from_path(path: &str) -> Self {
+        match Path::new(path).extension().and_then(|e| e.to_str()) {
+            Some("html") | Some("htm") => Document::Html,
+            _ => Document::Markdown,
+        }
+    }
+}
+
+/// What the report shows besides the runs
+#[derive(Clone, Copy, Default)]
+pub struct Settings<'a> {
+    /// Answers the runs are compared with
+    pub answers: Option<&'a Answers>,
+    /// Whether the answers are hidden, to share the report without spoiling them
+    pub mask: bool,
+    /// Git revision of the code which ran, if known
+    pub revision: Option<&'a str>,
+}
+
+/// Hides an answer, only keeping its length
+///
+/// # Examples
+/// ```
+/// use advent_of_code::report::mask;
+/// assert_eq!("******", mask("vmpywg"));
+/// ```
+pub fn mask(answer: &str) -> String {
+    answer.chars().map(|_| '*').collect()
+}
+
+/// Escapes the characters of a Markdown table cell
+fn escape_markdown(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace('\n', " ")
+}
+
+/// Escapes the characters of HTML text
+///
+/// # Examples
+/// ```
+/// use advent_of_code::report::escape_html;
+/// assert_eq!("a &lt;b&gt; &amp; &quot;c&quot;", escape_html("a <b> & \"c\""));
+/// ```
+pub fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Compares the answer of a run with the expected one
+fn status(run: &Run, answers: &Answers) -> Status {
+    let expected = answers.get(&(run.year, run.day, run.part));
+    verify::status(run, expected.map(|e| e.as_str()))
+}
+
+/// Cells of the row of a run, not escaped yet
+fn cells(run: &Run, settings: &Settings) -> Vec<String> {
+    let title = get_puzzle(run.year, run.day).map_or("", |p| p.title);
+    let answer = match run.answer {
+        Ok(ref answer) if settings.mask => mask(&answer.to_string()),
+        Ok(ref answer) => answer.to_string(),
+        Err(ref failure) => format!("FAILED ({})", failure),
+    };
+    let status = settings
+        .answers
+        .map_or(String::from("-"), |answers| status(run, answers).to_string());
+    vec![
+        run.year.to_string(),
+        run.day.to_string(),
+        run.part.to_string(),
+        title.to_string(),
+        answer,
+        status,
+        format_duration(run.input_time),
+        format_duration(run.parse_time),
+        format_duration(run.solve_time),
+    ]
+}
+
+/// Headers of the columns of the table
+const HEADERS: [&str; 9] = [
+    "Year", "Day", "Part", "Puzzle", "Answer", "Status", "Input", "Parse", "Solve",
+];
+
+/// Sentence summarizing the runs, under the title of the report
+fn summary(runs: &[Run], settings: &Settings) -> String {
+    let solved = runs.iter().filter(|r| r.answer.is_ok()).count();
+    let mut summary = format!("{} of {} parts solved", solved, runs.len());
+    if let Some(answers) = settings.answers {
+        let passed = runs.iter()
+            .filter(|r| status(r, answers) == Status::Pass)
+            .count();
+        summary += &format!(", {} matching the expected answers", passed);
+    }
+    let total = runs.iter()
+        .map(|r| r.input_time + r.parse_time + r.solve_time)
+        .sum::<Duration>();
+    summary += &format!(", in {}", format_duration(total));
+    if let Some(revision) = settings.revision {
+        summary += &format!(" (revision {})", revision);
+    }
+    summary
+}
+
+/// Renders the runs as a Markdown table
+fn render_markdown(runs: &[Run], settings: &Settings) -> String {
+    let mut report = format!("# Advent of Code\n\n{}.\n\n", summary(runs, settings));
+    report += &format!("| {} |\n", HEADERS.join(" | "));
+    report += "|-----:|----:|-----:|--------|--------|--------|------:|------:|------:|\n";
+    for run in runs {
+        let cells: Vec<String> = cells(run, settings)
+            .iter()
+            .map(|c| escape_markdown(c))
+            .collect();
+        report += &format!("| {} |\n", cells.join(" | "));
+    }
+    report
+}
+
+/// Renders the runs as an HTML page holding a table
+fn render_html(runs: &[Run], settings: &Settings) -> String {
+    let mut report = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Advent of Code</title>\n</head>\n<body>\n<h1>Advent of Code</h1>\n",
+    );
+    report += &format!("<p>{}.</p>\n<table>\n<tr>", escape_html(&summary(runs, settings)));
+    for header in &HEADERS {
+        report += &format!("<th>{}</th>", header);
+    }
+    report += "</tr>\n";
+    for run in runs {
+        report += "<tr>";
+        for cell in cells(run, settings) {
+            report += &format!("<td>{}</td>", escape_html(&cell));
+        }
+        report += "</tr>\n";
+    }
+    report += "</table>\n</body>\n</html>\n";
+    report
+}
+
+/// Renders a report of the runs, with a row per part
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use advent_of_code::report::{render, Document, Settings};
+/// use advent_of_code::runner::Run;
+/// use advent_of_code::verify::parse_answers;
+/// let run = Run {
+///     year: 2017,
+///     day: 7,
+///     part: 1,
+///     input: String::from("input/2017/day7"),
+///     tab_lines: vec![],
+///     answer: Ok("vmpywg".into()),
+///     input_time: Duration::default(),
+///     parse_time: Duration::default(),
+///     solve_time: Duration::from_millis(3),
+/// };
+/// let answers = parse_answers("2017 7 1 vmpywg").unwrap();
+/// let settings = Settings { answers: Some(&answers), mask: true, revision: None };
+/// let report = render(&[run], Document::Markdown, &settings);
+/// assert!(report.contains("| 2017 | 7 | 1 | Recursive Circus | ****** | PASS |"));
+/// assert!(report.contains("1 of 1 parts solved, 1 matching the expected answers"));
+/// ```
+pub fn render(runs: &[Run], document: Document, settings: &Settings) -> String {
+    match document {
+        Document::Markdown => render_markdown(runs, settings),
+        Document::Html => render_html(runs, settings),
+    }
+}
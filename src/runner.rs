@@ -0,0 +1,457 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
+use cancel::{self, CancelToken};
+use param;
+use progress;
+use puzzle::Parsed;
+use {get_input, Answer, Input, Puzzle, SolveError};
+
+/// Reason why a solver could not produce an answer
+#[derive(Clone)]
+pub enum Failure {
+    /// The input could not be read, or the solver returned an error
+    Error(SolveError),
+    /// The solver panicked, with the provided message
+    Panic(String),
+    /// The solver did not finish before the timeout
+    Timeout(Duration),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Failure::Error(ref e) => write!(f, "{}", e),
+            Failure::Panic(ref msg) => write!(f, "panicked: {}", msg),
+            Failure::Timeout(d) => write!(f, "timed out after {}", format_duration(d)),
+        }
+    }
+}
+
+/// Outcome of running the solver of a part on an input file
+pub struct Run {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    /// Path of the input file
+    pub input: String,
+    /// Lines of the input containing tabs, starting from 1
+    pub tab_lines: Vec<usize>,
+    pub answer: Result<Answer, Failure>,
+    /// Time spent reading the input file
+    pub input_time: Duration,
+    /// Time spent parsing the input, shared by all the parts of the day
+    pub parse_time: Duration,
+    /// Time spent in the solver
+    pub solve_time: Duration,
+}
+
+/// Extracts the message of a panic payload
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        String::from("unknown cause")
+    }
+}
+
+/// Calls the function, turning its error or panic into a failure
+fn catch<T, F>(f: F) -> Result<T, Failure>
+where
+    F: FnOnce() -> Result<T, SolveError>,
+{
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result.map_err(Failure::Error),
+        Err(e) => Err(Failure::Panic(panic_message(&*e))),
+    }
+}
+
+/// Settings shared by the runs
+#[derive(Clone, Default)]
+pub struct Options {
+    /// Time after which a part is stopped, if any
+    pub timeout: Option<Duration>,
+    /// How the solvers report their progress
+    pub progress: progress::Style,
+    /// Values given to the parameters of the puzzles
+    pub params: param::Values,
+}
+
+/// Year and day of a puzzle, with the function solving its parts
+type Solve = (u32, u32, fn(&Parsed, u32) -> Result<Answer, SolveError>);
+
+/// Solves a part from the parsed input on the current thread
+/// with the parameters of the options, reporting its progress and catching any panic
+fn solve_here(puzzle: Solve, parsed: &Parsed, part: u32, options: &Options) -> Result<Answer, Failure> {
+    let (year, day, solve) = puzzle;
+    let label = format!("{} day {} part {}", year, day, part);
+    progress::set_current(Some(options.progress.reporter(&label)));
+    param::set_current(options.params.clone());
+    let answer = catch(|| solve(parsed, part));
+    progress::finish();
+    progress::set_current(None);
+    param::set_current(param::Values::new());
+    answer
+}
+
+/// Solves a part from the parsed input, catching any panic.
+///
+/// With a timeout, the part is solved on its own thread.
+/// If it takes too long, its cancellation token is cancelled
+/// and the part is reported as timed out, without waiting for its thread:
+/// a solver which never checks the token keeps running in the background.
+fn solve(puzzle: &Puzzle, parsed: &Parsed, part: u32, options: &Options) -> Result<Answer, Failure> {
+    let puzzle = (puzzle.year, puzzle.day, puzzle.solve);
+    let timeout = match options.timeout {
+        Some(timeout) => timeout,
+        None => return solve_here(puzzle, parsed, part, options),
+    };
+
+    let token = CancelToken::new();
+    let (tx, rx) = mpsc::channel();
+    let (parsed, thread_token, options) = (parsed.clone(), token.clone(), options.clone());
+    thread::spawn(move || {
+        cancel::set_current(Some(thread_token));
+        // The runner does not wait for the answer after a timeout
+        let _ = tx.send(solve_here(puzzle, &parsed, part, &options));
+    });
+
+    match rx.recv_timeout(timeout) {
+        Ok(answer) => answer,
+        Err(_) => {
+            token.cancel();
+            Err(Failure::Timeout(timeout))
+        }
+    }
+}
+
+/// Reads and normalizes the content of the input file,
+/// mentioning the file name in the error if it cannot be read
+pub fn read_input(filename: &str) -> Result<Input, SolveError> {
+    get_input(filename).map_err(|e| {
+        let e = io::Error::new(e.kind(), format!("cannot read {}: {}", filename, e));
+        SolveError::Io(e)
+    })
+}
+
+/// Lists the named inputs stored in a directory, sorted by name,
+/// as pairs of an input name and the path of its file.
+/// Hidden files and subdirectories are ignored.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::runner::list_inputs;
+/// let inputs = list_inputs("input/2017").unwrap();
+/// assert_eq!(("day1".to_string(), "input/2017/day1".to_string()), inputs[0]);
+/// assert!(inputs.iter().any(|&(ref name, _)| name == "day25"));
+/// assert!(list_inputs("input").unwrap().iter().all(|&(ref name, _)| name != "2017"));
+/// ```
+pub fn list_inputs(dir: &str) -> Result<Vec<(String, String)>, SolveError> {
+    let cannot_read = |e: io::Error| {
+        SolveError::Io(io::Error::new(e.kind(), format!("cannot read {}: {}", dir, e)))
+    };
+    let mut inputs = Vec::new();
+    for entry in fs::read_dir(dir).map_err(cannot_read)? {
+        let entry = entry.map_err(cannot_read)?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if name.starts_with('.') || entry.path().is_dir() {
+            continue;
+        }
+        inputs.push((name, entry.path().to_string_lossy().into_owned()));
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+/// Input of a puzzle once read and parsed, shared by the runs of its parts
+struct Prepared {
+    parsed: Result<Parsed, Failure>,
+    tab_lines: Vec<usize>,
+    input_time: Duration,
+    parse_time: Duration,
+}
+
+/// Reads and parses the input file of the puzzle,
+/// catching any panic happening while parsing
+fn prepare(puzzle: &Puzzle, filename: &str) -> Prepared {
+    let start = Instant::now();
+    let input = read_input(filename);
+    let input_time = start.elapsed();
+    let tab_lines = input.as_ref().map_or(vec![], |input| input.tab_lines().to_vec());
+
+    let start = Instant::now();
+    let parsed = input
+        .map_err(Failure::Error)
+        .and_then(|input| catch(|| (puzzle.parse)(&input)));
+    Prepared {
+        parsed,
+        tab_lines,
+        input_time,
+        parse_time: start.elapsed(),
+    }
+}
+
+/// Runs a part of the puzzle on its prepared input,
+/// which reports its failure if it could not be read or parsed
+fn run_prepared(
+    puzzle: &Puzzle,
+    prepared: &Prepared,
+    part: u32,
+    filename: &str,
+    options: &Options,
+) -> Run {
+    let start = Instant::now();
+    let answer = match prepared.parsed {
+        Ok(ref parsed) => solve(puzzle, parsed, part, options),
+        Err(ref failure) => Err(failure.clone()),
+    };
+    Run {
+        year: puzzle.year,
+        day: puzzle.day,
+        part,
+        input: filename.to_string(),
+        tab_lines: prepared.tab_lines.clone(),
+        answer,
+        input_time: prepared.input_time,
+        parse_time: prepared.parse_time,
+        solve_time: start.elapsed(),
+    }
+}
+
+/// Runs the provided parts of the puzzle on the input file,
+/// parsing the input only once for all of them.
+/// Any panic happening while parsing or solving is caught.
+///
+/// If the input cannot be read or parsed, every part reports the failure.
+fn run_parts(puzzle: &Puzzle, parts: &[u32], filename: &str, options: &Options) -> Vec<Run> {
+    let prepared = prepare(puzzle, filename);
+    parts
+        .iter()
+        .map(|&part| run_prepared(puzzle, &prepared, part, filename, options))
+        .collect()
+}
+
+/// Runs every implemented part of the puzzle on the provided input file,
+/// parsing the input only once for all of them.
+/// Any panic happening while parsing or solving is caught,
+/// and each part is stopped after the timeout of the options if there is one.
+///
+/// If the input cannot be read or parsed, every part reports the failure.
+pub fn run(puzzle: &Puzzle, filename: &str, options: &Options) -> Vec<Run> {
+    let parts: Vec<u32> = puzzle.part_numbers().collect();
+    run_parts(puzzle, &parts, filename, options)
+}
+
+/// Runs a single part of the puzzle on the provided input file,
+/// catching any panic happening while parsing or solving,
+/// and stopping the part after the timeout of the options if there is one.
+///
+/// Returns None if the part has not been implemented.
+pub fn run_part(puzzle: &Puzzle, part: u32, filename: &str, options: &Options) -> Option<Run> {
+    puzzle.solver(part)?;
+    run_parts(puzzle, &[part], filename, options).pop()
+}
+
+/// Runs every implemented part of the provided puzzles
+/// on their default input file, in order.
+pub fn run_all<'a, I>(puzzles: I) -> Vec<Run>
+where
+    I: IntoIterator<Item = &'a Puzzle>,
+{
+    puzzles
+        .into_iter()
+        .flat_map(|p| run(p, p.input, &Options::default()))
+        .collect()
+}
+
+/// Runs every implemented part of the provided puzzles
+/// on their default input file, using up to `jobs` threads,
+/// and stopping each part after the timeout of the options if there is one.
+///
+/// Each part is a job of its own, handed to the threads as soon as they are free,
+/// so that both parts of a slow day can run at the same time.
+/// The input of a day is parsed only once, by the first job needing it,
+/// while the runs are returned in the same order as with `run_all`.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::get_puzzle;
+/// use advent_of_code::runner::run_parallel;
+/// let puzzles = vec![get_puzzle(2017, 3).unwrap(), get_puzzle(2017, 1).unwrap()];
+/// let runs = run_parallel(puzzles, 2, &Default::default());
+/// let order: Vec<(u32, u32)> = runs.iter().map(|r| (r.day, r.part)).collect();
+/// assert_eq!(vec![(3, 1), (3, 2), (1, 1), (1, 2)], order);
+/// ```
+pub fn run_parallel<'a, I>(puzzles: I, jobs: usize, options: &Options) -> Vec<Run>
+where
+    I: IntoIterator<Item = &'a Puzzle>,
+{
+    let puzzles: Vec<&Puzzle> = puzzles.into_iter().collect();
+    let prepared: Vec<OnceLock<Prepared>> = puzzles.iter().map(|_| OnceLock::new()).collect();
+    let parts: Vec<(usize, u32)> = puzzles
+        .iter()
+        .enumerate()
+        .flat_map(|(i, p)| p.part_numbers().map(move |part| (i, part)))
+        .collect();
+    let next = AtomicUsize::new(0);
+
+    let mut done: Vec<(usize, Run)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.max(1).min(parts.len()))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let job = next.fetch_add(1, Ordering::SeqCst);
+                        let (i, part) = match parts.get(job) {
+                            Some(&(i, part)) => (i, part),
+                            None => return done,
+                        };
+                        let p = puzzles[i];
+                        let input = prepared[i].get_or_init(|| prepare(p, p.input));
+                        done.push((job, run_prepared(p, input, part, p.input, options)));
+                    }
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|w| w.join().expect("Panics are caught by the runner"))
+            .collect()
+    });
+
+    done.sort_by_key(|&(job, _)| job);
+    done.into_iter().map(|(_, run)| run).collect()
+}
+
+/// Escapes a string as a JSON string literal
+///
+/// # Examples
+/// ```
+/// use advent_of_code::runner::json_string;
+/// assert_eq!(r#""line 1: \"a\tb\"\n""#, json_string("line 1: \"a\tb\"\n"));
+/// ```
+pub fn json_string(s: &str) -> String {
+    let mut json = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => json += "\\\"",
+            '\\' => json += "\\\\",
+            '\n' => json += "\\n",
+            '\r' => json += "\\r",
+            '\t' => json += "\\t",
+            c if (c as u32) < 0x20 => json += &format!("\\u{:04x}", c as u32),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+impl Run {
+    /// Renders the run as a JSON object on a single line,
+    /// with the durations in nanoseconds.
+    /// Either the answer or the error is null,
+    /// and the answer is a JSON number if it is an integer.
+    /// The lines of the input containing tabs are listed, as they may have been
+    /// meant as spaces.
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::get_puzzle;
+    /// use advent_of_code::runner::run;
+    /// let runs = run(get_puzzle(2017, 2).unwrap(), "input/2017/day2", &Default::default());
+    /// assert!(runs[0].to_json().contains(r#""tab_lines":[1,2,3,"#));
+    /// let runs = run(get_puzzle(2017, 1).unwrap(), "input/2017/day1", &Default::default());
+    /// assert!(runs[0].to_json().contains(r#""tab_lines":[],"answer":"#));
+    /// ```
+    pub fn to_json(&self) -> String {
+        let (answer, error) = match self.answer {
+            Ok(Answer::Integer(n)) => (n.to_string(), String::from("null")),
+            Ok(ref answer) => (json_string(&answer.to_string()), String::from("null")),
+            Err(ref failure) => (String::from("null"), json_string(&failure.to_string())),
+        };
+        let tab_lines: Vec<String> = self.tab_lines.iter().map(|l| l.to_string()).collect();
+        format!(
+            "{{\"year\":{},\"day\":{},\"part\":{},\"input\":{},\"tab_lines\":[{}],\
+             \"answer\":{},\"error\":{},\"input_ns\":{},\"parse_ns\":{},\"solve_ns\":{}}}",
+            self.year,
+            self.day,
+            self.part,
+            json_string(&self.input),
+            tab_lines.join(","),
+            answer,
+            error,
+            self.input_time.as_nanos(),
+            self.parse_time.as_nanos(),
+            self.solve_time.as_nanos()
+        )
+    }
+}
+
+/// Formats a duration with a unit adapted to its magnitude
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use advent_of_code::runner::format_duration;
+/// assert_eq!("850ns", format_duration(Duration::new(0, 850)));
+/// assert_eq!("12.5µs", format_duration(Duration::new(0, 12_500)));
+/// assert_eq!("3.2ms", format_duration(Duration::new(0, 3_200_000)));
+/// assert_eq!("61.0s", format_duration(Duration::new(61, 0)));
+/// ```
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_secs() as f64 * 1e9 + f64::from(d.subsec_nanos());
+    if nanos < 1e3 {
+        format!("{}ns", nanos)
+    } else if nanos < 1e6 {
+        format!("{:.1}µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.1}ms", nanos / 1e6)
+    } else {
+        format!("{:.1}s", nanos / 1e9)
+    }
+}
+
+/// Renders the runs as a text table, one line per part
+pub fn format_table(runs: &[Run]) -> String {
+    let answers: Vec<String> = runs.iter()
+        .map(|r| match r.answer {
+            Ok(ref answer) => answer.to_string(),
+            Err(ref failure) => format!("FAILED ({})", failure),
+        })
+        .collect();
+    let width = answers.iter().map(|a| a.chars().count()).max().unwrap_or(0).max(6);
+
+    let mut table = format!(
+        "{:>4} {:>3} {:>4}  {:<width$}  {:>9}  {:>9}  {:>9}\n",
+        "Year",
+        "Day",
+        "Part",
+        "Answer",
+        "Input",
+        "Parse",
+        "Solve",
+        width = width
+    );
+    for (r, answer) in runs.iter().zip(answers) {
+        table += &format!(
+            "{:>4} {:>3} {:>4}  {:<width$}  {:>9}  {:>9}  {:>9}\n",
+            r.year,
+            r.day,
+            r.part,
+            answer,
+            format_duration(r.input_time),
+            format_duration(r.parse_time),
+            format_duration(r.solve_time),
+            width = width
+        );
+    }
+    table
+}
@@ -0,0 +1,299 @@
+use std::fmt;
+use std::str::FromStr;
+use SolveError;
+
+/// Cursor reading a line of the input token by token.
+/// Every reading method first skips the whitespace before its token,
+/// and reports a failure with the line and column of the token.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::scan::Scanner;
+/// let mut line = Scanner::new(1, "fwft (72) -> ktlj, cntj");
+/// assert_eq!("fwft", line.name().unwrap());
+/// line.expect("(").unwrap();
+/// assert_eq!(72, line.number::<u32>().unwrap());
+/// line.expect(")").unwrap();
+/// assert!(line.eat("->"));
+/// assert_eq!(vec!["ktlj", "cntj"], line.separated(",", |l| l.name()).unwrap());
+/// assert!(line.end().is_ok());
+/// ```
+pub struct Scanner<'a> {
+    line: usize,
+    text: &'a str,
+    /// Byte offset of the cursor in the text
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    /// Creates a scanner at the start of the line numbered `line` from 1
+    pub fn new(line: usize, text: &'a str) -> Self {
+        Scanner { line, text, pos: 0 }
+    }
+
+    /// Number of the character under the cursor, starting from 1
+    pub fn column(&self) -> usize {
+        self.text[..self.pos].chars().count() + 1
+    }
+
+    /// Error at the column, holding the whole line
+    pub fn error_at<T: fmt::Display>(&self, column: usize, reason: T) -> SolveError {
+        SolveError::parse_at(self.line, column, self.text, reason)
+    }
+
+    /// Error at the cursor, holding the whole line
+    pub fn error<T: fmt::Display>(&self, reason: T) -> SolveError {
+        self.error_at(self.column(), reason)
+    }
+
+    /// Text after the cursor
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    /// Moves the cursor past the whitespace,
+    /// for the column to be the one of the next token
+    pub fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Whether only whitespace is left on the line
+    pub fn at_end(&mut self) -> bool {
+        self.skip_whitespace();
+        self.pos == self.text.len()
+    }
+
+    /// Checks that only whitespace is left on the line
+    pub fn end(&mut self) -> Result<(), SolveError> {
+        if self.at_end() {
+            Ok(())
+        } else {
+            Err(self.error(format!("Unexpected {:?}", self.rest())))
+        }
+    }
+
+    /// Reads the literal if it comes next, and returns whether it did
+    pub fn eat(&mut self, literal: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(literal) {
+            self.pos += literal.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Reads the literal, which must come next
+    pub fn expect(&mut self, literal: &str) -> Result<(), SolveError> {
+        if self.eat(literal) {
+            Ok(())
+        } else {
+            Err(self.error(format!("Expected {:?}", literal)))
+        }
+    }
+
+    /// Reads the characters matching the predicate, possibly none
+    pub fn take_while<P>(&mut self, predicate: P) -> &'a str
+    where
+        P: Fn(char) -> bool,
+    {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    /// Reads a token made of the characters matching the predicate,
+    /// failing with the description of the token if there is none
+    pub fn token<P>(&mut self, what: &str, predicate: P) -> Result<&'a str, SolveError>
+    where
+        P: Fn(char) -> bool,
+    {
+        match self.take_while(predicate) {
+            "" => Err(self.error(format!("Expected {}", what))),
+            token => Ok(token),
+        }
+    }
+
+    /// Reads a name made of letters, digits and underscores
+    pub fn name(&mut self) -> Result<&'a str, SolveError> {
+        self.token("a name", |c| c.is_alphanumeric() || c == '_')
+    }
+
+    /// Reads any character but whitespace
+    pub fn char(&mut self) -> Result<char, SolveError> {
+        self.skip_whitespace();
+        match self.rest().chars().next() {
+            Some(c) => {
+                self.pos += c.len_utf8();
+                Ok(c)
+            }
+            None => Err(self.error("Expected a character")),
+        }
+    }
+
+    /// Reads a single letter, such as the name of a register,
+    /// which must not be followed by other letters or digits
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::scan::Scanner;
+    /// assert_eq!('e', Scanner::new(1, " e/b").letter().unwrap());
+    /// let mut line = Scanner::new(4, "set ab 1");
+    /// assert_eq!("set", line.name().unwrap());
+    /// let e = line.letter().unwrap_err();
+    /// assert_eq!("line 4, column 5: Expected a single letter: \"set ab 1\"", e.to_string());
+    /// ```
+    pub fn letter(&mut self) -> Result<char, SolveError> {
+        self.skip_whitespace();
+        let mut chars = self.rest().chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), next) if c.is_alphabetic() && !next.is_some_and(char::is_alphanumeric) => {
+                self.pos += c.len_utf8();
+                Ok(c)
+            }
+            _ => Err(self.error("Expected a single letter")),
+        }
+    }
+
+    /// Whether a number comes next
+    pub fn at_number(&mut self) -> bool {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let digits = rest.strip_prefix(['-', '+']).unwrap_or(rest);
+        digits.starts_with(|c: char| c.is_ascii_digit())
+    }
+
+    /// Reads an integer, with an optional sign
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::scan::Scanner;
+    /// let mut line = Scanner::new(1, "p=< 3,-12,+0>");
+    /// line.expect("p=<").unwrap();
+    /// assert_eq!(3, line.number::<i32>().unwrap());
+    /// line.expect(",").unwrap();
+    /// assert_eq!(-12, line.number::<i32>().unwrap());
+    /// line.expect(",").unwrap();
+    /// assert_eq!(0, line.number::<i32>().unwrap());
+    /// let e = Scanner::new(2, "12 x").repeat(|l| l.number::<u8>()).unwrap_err();
+    /// assert_eq!("line 2, column 4: Expected a number: \"12 x\"", e.to_string());
+    /// let e = Scanner::new(3, "256").number::<u8>().unwrap_err();
+    /// assert_eq!(
+    ///     "line 3, column 1: Could not parse \"256\" as a number (number too large to fit in target type): \"256\"",
+    ///     e.to_string()
+    /// );
+    /// ```
+    pub fn number<T>(&mut self) -> Result<T, SolveError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        if !self.at_number() {
+            return Err(self.error("Expected a number"));
+        }
+        let column = self.column();
+        let rest = self.rest();
+        let sign = if rest.starts_with(['-', '+']) { 1 } else { 0 };
+        let len = rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(rest.len(), |len| sign + len);
+        self.pos += len;
+        rest[..len].parse().map_err(|e| {
+            self.error_at(column, format!("Could not parse {:?} as a number ({})", &rest[..len], e))
+        })
+    }
+
+    /// Reads items separated by the separator, at least one
+    pub fn separated<T, F>(&mut self, separator: &str, mut item: F) -> Result<Vec<T>, SolveError>
+    where
+        F: FnMut(&mut Self) -> Result<T, SolveError>,
+    {
+        let mut items = vec![item(self)?];
+        while self.eat(separator) {
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    /// Reads items until the end of the line, possibly none
+    pub fn repeat<T, F>(&mut self, mut item: F) -> Result<Vec<T>, SolveError>
+    where
+        F: FnMut(&mut Self) -> Result<T, SolveError>,
+    {
+        let mut items = Vec::new();
+        while !self.at_end() {
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+}
+
+/// Parses every line of the input with the provided function,
+/// which must read the line until its end.
+/// An input made only of whitespace is rejected, as no puzzle has an empty input.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::scan::{lines, Scanner};
+/// use advent_of_code::SolveError;
+/// fn layer(l: &mut Scanner) -> Result<(u32, u32), SolveError> {
+///     let depth = l.number()?;
+///     l.expect(":")?;
+///     Ok((depth, l.number()?))
+/// }
+/// assert_eq!(vec![(0, 3), (1, 2)], lines("0: 3\n1: 2", layer).unwrap());
+/// let e = lines("0: 3\n1: 2 4", layer).unwrap_err();
+/// assert_eq!("line 2, column 6: Unexpected \"4\": \"1: 2 4\"", e.to_string());
+/// assert!(lines(" \n", layer).is_err());
+/// ```
+pub fn lines<'a, T, F>(s: &'a str, mut parse: F) -> Result<Vec<T>, SolveError>
+where
+    F: FnMut(&mut Scanner<'a>) -> Result<T, SolveError>,
+{
+    if s.trim().is_empty() {
+        return Err(SolveError::InvalidInput(String::from("The input is empty")));
+    }
+    s.lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let mut scanner = Scanner::new(i + 1, line);
+            let value = parse(&mut scanner)?;
+            scanner.end()?;
+            Ok(value)
+        })
+        .collect()
+}
+
+/// Parses an input made of a single line with the provided function,
+/// which must read the line until its end
+pub fn line<'a, T, F>(s: &'a str, parse: F) -> Result<T, SolveError>
+where
+    F: FnMut(&mut Scanner<'a>) -> Result<T, SolveError>,
+{
+    if s.lines().count() > 1 {
+        return Err(SolveError::InvalidInput(String::from(
+            "The input should be on a single line",
+        )));
+    }
+    lines(s, parse).map(|mut values| values.remove(0))
+}
+
+/// Reads every number of the input, separated by whitespace
+///
+/// # Examples
+/// ```
+/// use advent_of_code::scan::numbers;
+/// assert_eq!(vec![0, 3, 0, 1, -3], numbers::<i32>("0 3\n0 1 -3").unwrap());
+/// assert!(numbers::<i32>("0 3\n0 x -3").is_err());
+/// ```
+pub fn numbers<T>(s: &str) -> Result<Vec<T>, SolveError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let rows = lines(s, |l| l.repeat(|l| l.number()))?;
+    Ok(rows.into_iter().flatten().collect())
+}
@@ -0,0 +1,164 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use runner::Run;
+
+/// Recorded answers, indexed by year, day and part
+pub type Answers = BTreeMap<(u32, u32, u32), String>;
+
+/// Parses a single answer line, formatted as `year day part answer`
+fn parse_answer(line: &str) -> Result<((u32, u32, u32), String), String> {
+    let mut tokens = line.split_whitespace();
+    let year = tokens
+        .next()
+        .ok_or("Missing year")?
+        .parse()
+        .map_err(|e| format!("Could not parse year as int: {}", e))?;
+    let day = tokens
+        .next()
+        .ok_or("Missing day")?
+        .parse()
+        .map_err(|e| format!("Could not parse day as int: {}", e))?;
+    let part = tokens
+        .next()
+        .ok_or("Missing part")?
+        .parse()
+        .map_err(|e| format!("Could not parse part as int: {}", e))?;
+    let answer = tokens.next().ok_or("Missing answer")?;
+    if tokens.next().is_some() {
+        Err(format!("Unexpected content after the answer: {}", line))?
+    }
+    Ok(((year, day, part), answer.to_string()))
+}
+
+/// Parses an answers file, one `year day part answer` line per answer.
+/// Empty lines and lines starting with `#` are ignored.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::verify::parse_answers;
+/// let s = "\
+/// ## Recorded answers
+/// 2017 1 1 1031
+///
+/// 2017 7 1 vmpywg";
+/// let answers = parse_answers(s).unwrap();
+/// assert_eq!(2, answers.len());
+/// assert_eq!("vmpywg", answers[&(2017, 7, 1)]);
+/// assert!(parse_answers("7 1 vmpywg").is_err());
+/// ```
+pub fn parse_answers(s: &str) -> Result<Answers, String> {
+    s.lines()
+        .enumerate()
+        .filter(|&(_, line)| !line.trim().is_empty() && !line.trim().starts_with('#'))
+        .map(|(i, line)| parse_answer(line).map_err(|e| format!("line {}: {}", i + 1, e)))
+        .collect()
+}
+
+/// Result of the comparison of a solver output with the recorded answer
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    /// The output matches the recorded answer
+    Pass,
+    /// The output stands for the recorded answer,
+    /// but is not formatted the same way
+    Format,
+    /// The output differs from the recorded answer
+    Fail,
+    /// Either the answer has not been recorded,
+    /// or no solver has been implemented for it
+    Missing,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            Status::Pass => "PASS",
+            Status::Format => "FORMAT",
+            Status::Fail => "FAIL",
+            Status::Missing => "MISSING",
+        };
+        f.pad(s)
+    }
+}
+
+/// Verdict for a single year, day and part
+pub struct Verdict {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub status: Status,
+    /// Output of the solver, or failure description
+    pub actual: Option<String>,
+    /// Recorded answer
+    pub expected: Option<String>,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:<7} {:>4} {:>3} {:>2}",
+            self.status, self.year, self.day, self.part
+        )?;
+        match (&self.actual, &self.expected) {
+            (Some(actual), Some(expected))
+                if self.status == Status::Fail || self.status == Status::Format =>
+            {
+                write!(f, "  {} (expected {})", actual, expected)
+            }
+            (Some(actual), _) => write!(f, "  {}", actual),
+            (None, Some(expected)) => write!(f, "  no solver (expected {})", expected),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// Compares the answer of a run with the recorded one, if any
+pub fn status(run: &Run, expected: Option<&str>) -> Status {
+    match (&run.answer, expected) {
+        (Ok(answer), Some(expected)) if *answer == expected => Status::Pass,
+        (Ok(answer), Some(expected)) if answer.matches(expected) => Status::Format,
+        (Ok(_), None) => Status::Missing,
+        (Ok(_), Some(_)) | (Err(_), _) => Status::Fail,
+    }
+}
+
+/// Compares the runs with the recorded answers.
+///
+/// Every run gets a verdict, and so does every recorded answer
+/// which has not been run, ordered by year, day and part.
+pub fn verify(runs: &[Run], answers: &Answers) -> Vec<Verdict> {
+    let mut verdicts: Vec<Verdict> = runs.iter()
+        .map(|r| {
+            let expected = answers.get(&(r.year, r.day, r.part)).cloned();
+            let actual = match r.answer {
+                Ok(ref answer) => answer.to_string(),
+                Err(ref failure) => format!("FAILED ({})", failure),
+            };
+            Verdict {
+                year: r.year,
+                day: r.day,
+                part: r.part,
+                status: status(r, expected.as_deref()),
+                actual: Some(actual),
+                expected,
+            }
+        })
+        .collect();
+
+    for (&(year, day, part), expected) in answers {
+        if !runs.iter().any(|r| r.year == year && r.day == day && r.part == part) {
+            verdicts.push(Verdict {
+                year,
+                day,
+                part,
+                status: Status::Missing,
+                actual: None,
+                expected: Some(expected.clone()),
+            });
+        }
+    }
+
+    verdicts.sort_by_key(|v| (v.year, v.day, v.part));
+    verdicts
+}
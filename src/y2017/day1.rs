@@ -0,0 +1,102 @@
+use generate::Rng;
+use puzzle::{self, Day, Puzzle};
+use scan::{self, Scanner};
+use {Answer, SolveError};
+
+/// Day 1: Inverse Captcha
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 1,
+    title: "Inverse Captcha",
+    description: "Sum the digits matching the next, then the opposite, digit of a circular sequence",
+    input: "input/2017/day1",
+    parts: &[one, two],
+    params: &[],
+    generate,
+    checks: &[],
+    parse: puzzle::parse::<Day1>,
+    solve: puzzle::solve::<Day1>,
+};
+
+/// Solver of the captcha, working on the digits of the sequence
+pub struct Day1;
+
+impl Day for Day1 {
+    type Input = Vec<u32>;
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        scan::line(s, digits)
+    }
+
+    fn part1(coll: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(captcha(coll, 1).into())
+    }
+
+    fn part2(coll: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(captcha(coll, coll.len() / 2).into())
+    }
+}
+
+/// Reads the digits of the sequence, which must be followed by nothing else
+fn digits(l: &mut Scanner) -> Result<Vec<u32>, SolveError> {
+    let digits = l.take_while(|c| c.is_ascii_digit());
+    if !l.at_end() {
+        return Err(l.error("Expected a digit"));
+    }
+    // We create a vector of the digits of the input
+    Ok(digits.chars().filter_map(|c| c.to_digit(10)).collect())
+}
+
+/// Sums the digits matching the digit located `offset` positions further
+fn captcha(coll: &[u32], offset: usize) -> u32 {
+    let n = coll.len();
+
+    // We create a first iterator on the collection
+    let first = coll.iter();
+    // Then a second iterator which skips the first characters and puts them at the end
+    let second = coll.iter().cycle().skip(offset).take(n);
+
+    // We take the sum of the "duplicated" characters
+    first
+        .zip(second)
+        .filter(|&(&x, &y)| x == y)
+        .map(|(&x, _)| x)
+        .sum()
+}
+
+/// Computes the "captcha sum" of a string representing a sequence of digits.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day1::one;
+/// assert_eq!("3", one("1122").unwrap());
+/// assert_eq!("4", one("1111").unwrap());
+/// assert_eq!("0", one("1234").unwrap());
+/// assert_eq!("9", one("91212129").unwrap());
+/// assert!(one("12a").is_err());
+/// ```
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day1::part1(&puzzle::read::<Day1>(s)?)
+}
+
+/// Same thing, but according to the second algorithm
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day1::two;
+/// assert_eq!("6", two("1212").unwrap());
+/// assert_eq!("0", two("1221").unwrap());
+/// assert_eq!("4", two("123425").unwrap());
+/// assert_eq!("12", two("123123").unwrap());
+/// assert_eq!("4", two("12131415").unwrap());
+/// ```
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day1::part2(&puzzle::read::<Day1>(s)?)
+}
+
+/// Generates a random sequence of digits
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    (0..2000 * scale)
+        .map(|_| (b'0' + rng.below(10) as u8) as char)
+        .collect()
+}
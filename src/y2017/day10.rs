@@ -1,3 +1,31 @@
+use generate::Rng;
+use param::{self, Param};
+use puzzle::{self, Day, Puzzle};
+use scan;
+use {Answer, SolveError};
+
+/// Day 10: Knot Hash
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 10,
+    title: "Knot Hash",
+    description: "Product of the first numbers after a round of knots, then the complete knot hash",
+    input: "input/2017/day10",
+    parts: &[one, two],
+    params: &[SIZE],
+    generate,
+    checks: &[],
+    parse: puzzle::parse::<Day10>,
+    solve: puzzle::solve::<Day10>,
+};
+
+/// Number of marks on the list of the first part
+pub const SIZE: Param = Param {
+    name: "size",
+    description: "number of marks on the list of the first part, up to 256",
+    defaults: &[256],
+};
+
 /// Creates an initial list, with increasing values
 pub fn new_list() -> Vec<u8> {
     (0..256).map(|x| x as u8).collect()
@@ -7,13 +35,13 @@ pub fn new_list() -> Vec<u8> {
 ///
 /// # Examples
 /// ```
-/// use advent_of_code::day10::{new_list, knot};
+/// use advent_of_code::y2017::day10::{new_list, knot};
 /// let mut list = new_list();
 /// knot(&mut list, 10, 10);
 /// let slice = [19, 18, 17, 16, 15, 14, 13, 12, 11, 10];
 /// assert_eq!(list[10..20], slice);
 /// ```
-pub fn knot(list: &mut Vec<u8>, position: usize, length: usize) {
+pub fn knot(list: &mut [u8], position: usize, length: usize) {
     for i in 0..length / 2 {
         let n1 = (position + i) % list.len();
         let n2 = (position + length - 1 - i) % list.len();
@@ -25,7 +53,7 @@ pub fn knot(list: &mut Vec<u8>, position: usize, length: usize) {
 ///
 /// # Examples
 /// ```
-/// use advent_of_code::day10::hash_round;
+/// use advent_of_code::y2017::day10::hash_round;
 /// let (list, last_pos, last_skip) = hash_round(&vec![3, 3, 3]);
 /// let slice = [2, 1, 0, 5, 4, 3, 6, 9, 8, 7];
 /// assert_eq!(list[0..10], slice);
@@ -33,7 +61,11 @@ pub fn knot(list: &mut Vec<u8>, position: usize, length: usize) {
 /// assert_eq!(3, last_skip);
 /// ```
 pub fn hash_round(lengths: &[usize]) -> (Vec<u8>, usize, usize) {
-    let mut list = new_list();
+    hash_round_on(new_list(), lengths)
+}
+
+/// Ties the knots of a round in the provided list
+fn hash_round_on(mut list: Vec<u8>, lengths: &[usize]) -> (Vec<u8>, usize, usize) {
     let mut position = 0;
     let mut skip = 0;
     for &length in lengths {
@@ -48,31 +80,57 @@ pub fn hash_round(lengths: &[usize]) -> (Vec<u8>, usize, usize) {
 ///
 /// # Examples
 /// ```
-/// use advent_of_code::day10::parse_lengths;
+/// use advent_of_code::y2017::day10::parse_lengths;
 /// let s = "3, 4, 1, 5";
 /// let v = vec![3, 4, 1, 5];
-/// assert_eq!(v, parse_lengths(s));
+/// assert_eq!(v, parse_lengths(s).unwrap());
+/// assert!(parse_lengths("3, 4,, 5").is_err());
 /// ```
-pub fn parse_lengths(s: &str) -> Vec<usize> {
-    s.trim()
-        .split(',')
-        .filter_map(|s| s.trim().parse().ok())
-        .collect()
+pub fn parse_lengths(s: &str) -> Result<Vec<usize>, SolveError> {
+    scan::line(s, |l| l.separated(",", |l| l.number()))
+}
+
+/// Solver of the knot hash, working on the raw input
+/// which is read as lengths or as ascii depending on the part
+pub struct Day10;
+
+impl Day for Day10 {
+    type Input = String;
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        Ok(String::from(s))
+    }
+
+    fn part1(s: &Self::Input) -> Result<Answer, SolveError> {
+        let size: usize = param::get(&SIZE, 1)?;
+        let lengths = parse_lengths(s)?;
+        if !(2..=256).contains(&size) || lengths.iter().any(|&l| l > size) {
+            return Err(SolveError::InvalidInput(format!(
+                "The list should have between 2 and 256 marks, and at least as many as each length, not {}",
+                size
+            )));
+        }
+        let list = (0..size).map(|x| x as u8).collect();
+        let (list, _, _) = hash_round_on(list, &lengths);
+        Ok((usize::from(list[0]) * usize::from(list[1])).into())
+    }
+
+    fn part2(s: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(Answer::Digest(knot_hash(s)))
+    }
 }
 
 /// Calculates the product of the two first numbers
 /// of the hash generated with the provided lengths
-pub fn one(s: &str) -> String {
-    let lengths = parse_lengths(s);
-    let (list, _, _) = hash_round(&lengths);
-    (list[0] * list[1]).to_string()
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day10::part1(&puzzle::read::<Day10>(s)?)
 }
 
 /// Parses a list of ascii characters into lengths
 ///
 /// # Examples
 /// ```
-/// use advent_of_code::day10::parse_ascii;
+/// use advent_of_code::y2017::day10::parse_ascii;
 /// let s = "1,2,3";
 /// let v = vec![49,44,50,44,51];
 /// assert_eq!(v, parse_ascii(s));
@@ -80,7 +138,7 @@ pub fn one(s: &str) -> String {
 pub fn parse_ascii(s: &str) -> Vec<usize> {
     s.trim()
         .as_bytes()
-        .into_iter()
+        .iter()
         .map(|&l| l as usize)
         .collect()
 }
@@ -109,7 +167,7 @@ impl Hasher {
 ///
 /// # Examples
 /// ```
-/// use advent_of_code::day10::densify;
+/// use advent_of_code::y2017::day10::densify;
 /// let sparse = vec![65, 27, 9, 1, 4, 3, 40, 50, 91, 7, 6, 0, 2, 5, 68, 22];
 /// assert_eq!(vec![64], densify(sparse));
 /// ```
@@ -155,14 +213,20 @@ pub fn knot_hash(s: &str) -> Vec<u8> {
 ///
 /// # Examples
 /// ```
-/// use advent_of_code::day10::two;
-/// assert_eq!(two(""), "a2582a3a0e66e6e86e3812dcb672a272");
-/// assert_eq!(two("AoC 2017"), "33efeb34ea91902bb2f59c9920caa6cd");
-/// assert_eq!(two("1,2,3"), "3efbe78a8d82f29979031a4aa0b16a9d");
-/// assert_eq!(two("1,2,4"), "63960835bcdc130f0b66d7ff4f6a5a8e");
+/// use advent_of_code::y2017::day10::two;
+/// assert_eq!(two("").unwrap(), "a2582a3a0e66e6e86e3812dcb672a272");
+/// assert_eq!(two("AoC 2017").unwrap(), "33efeb34ea91902bb2f59c9920caa6cd");
+/// assert_eq!(two("1,2,3").unwrap(), "3efbe78a8d82f29979031a4aa0b16a9d");
+/// assert_eq!(two("1,2,4").unwrap(), "63960835bcdc130f0b66d7ff4f6a5a8e");
 /// ```
-pub fn two(s: &str) -> String {
-    knot_hash(s)
-        .into_iter()
-        .fold(String::new(), |s, u| s + &format!("{:02x}", u))
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day10::part2(&puzzle::read::<Day10>(s)?)
+}
+
+/// Generates random lengths, within the size of the list
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let lengths: Vec<String> = (0..16 * scale)
+        .map(|_| rng.range(0, 255).to_string())
+        .collect();
+    lengths.join(",")
 }
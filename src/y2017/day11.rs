@@ -1,7 +1,26 @@
 use self::Direction::*;
+use generate::Rng;
+use puzzle::{self, Day, Puzzle};
+use scan::{self, Scanner};
+use {Answer, SolveError};
+
+/// Day 11: Hex Ed
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 11,
+    title: "Hex Ed",
+    description: "Distance reached on a hexagonal grid after following a path, then the furthest distance",
+    input: "input/2017/day11",
+    parts: &[one, two],
+    params: &[],
+    generate,
+    checks: &[],
+    parse: puzzle::parse::<Day11>,
+    solve: puzzle::solve::<Day11>,
+};
 
 /// The six possible directions on the grid
-enum Direction {
+pub enum Direction {
     NE,
     N,
     NW,
@@ -150,22 +169,53 @@ impl Path {
     }
 }
 
-/// Parses a single direction string
-fn parse_dir(s: &str) -> Direction {
-    match s {
-        "ne" => NE,
-        "n" => N,
-        "nw" => NW,
-        "sw" => SW,
-        "s" => S,
-        "se" => SE,
-        _ => panic!("Unable to parse direction: '{}'", s),
+/// Parses a single direction
+fn parse_dir(l: &mut Scanner) -> Result<Direction, SolveError> {
+    l.skip_whitespace();
+    let column = l.column();
+    match l.name()? {
+        "ne" => Ok(NE),
+        "n" => Ok(N),
+        "nw" => Ok(NW),
+        "sw" => Ok(SW),
+        "s" => Ok(S),
+        "se" => Ok(SE),
+        _ => Err(l.error_at(column, "Unable to parse direction")),
     }
 }
 
-/// Parses a list of directions
-fn parse_path(s: &str) -> Vec<Direction> {
-    s.trim().split(',').map(|s| parse_dir(s.trim())).collect()
+/// Parses a list of comma-separated directions
+fn parse_path(s: &str) -> Result<Vec<Direction>, SolveError> {
+    scan::line(s, |l| l.separated(",", parse_dir))
+}
+
+/// Solver of the hex grid, working on the path of the child
+pub struct Day11;
+
+impl Day for Day11 {
+    type Input = Vec<Direction>;
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        parse_path(s)
+    }
+
+    fn part1(dirs: &Self::Input) -> Result<Answer, SolveError> {
+        let mut path = dirs.iter().fold(Path::new(), |mut path, dir| {
+            path.step(dir);
+            path
+        });
+        Ok(path.shortest_dist().into())
+    }
+
+    fn part2(dirs: &Self::Input) -> Result<Answer, SolveError> {
+        let mut path = Path::new();
+        let mut max_dist = 0;
+        for dir in dirs {
+            path.short_step(dir);
+            max_dist = max_dist.max(path.dist());
+        }
+        Ok(max_dist.into())
+    }
 }
 
 /// Finds the minimal number of steps to reach a child
@@ -173,39 +223,42 @@ fn parse_path(s: &str) -> Vec<Direction> {
 ///
 /// # Examples
 /// ```
-/// use advent_of_code::day11::one;
-/// assert_eq!("3", one("ne,ne,ne"));
-/// assert_eq!("0", one("ne,ne,sw,sw"));
-/// assert_eq!("2", one("ne,ne,s,s"));
-/// assert_eq!("3", one("se,sw,se,sw,sw"));
+/// use advent_of_code::y2017::day11::one;
+/// assert_eq!("3", one("ne,ne,ne").unwrap());
+/// assert_eq!("0", one("ne,ne,sw,sw").unwrap());
+/// assert_eq!("2", one("ne,ne,s,s").unwrap());
+/// assert_eq!("3", one("se,sw,se,sw,sw").unwrap());
 /// ```
-pub fn one(s: &str) -> String {
-    let mut path = parse_path(s)
-        .into_iter()
-        .fold(Path::new(), |mut path, ref dir| {
-            path.step(dir);
-            path
-        });
-    path.shortest_dist().to_string()
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day11::part1(&puzzle::read::<Day11>(s)?)
 }
 
 /// Finds the maximal distance at which the child ever was.
 ///
 /// # Examples
 /// ```
-/// use advent_of_code::day11::two;
-/// assert_eq!("3", two("ne,ne,ne"));
-/// assert_eq!("2", two("ne,ne,sw,sw"));
-/// assert_eq!("2", two("ne,ne,s,s"));
-/// assert_eq!("3", two("sw,sw,sw,ne,se"));
+/// use advent_of_code::y2017::day11::two;
+/// assert_eq!("3", two("ne,ne,ne").unwrap());
+/// assert_eq!("2", two("ne,ne,sw,sw").unwrap());
+/// assert_eq!("2", two("ne,ne,s,s").unwrap());
+/// assert_eq!("3", two("sw,sw,sw,ne,se").unwrap());
 /// ```
-pub fn two(s: &str) -> String {
-    let dirs = parse_path(s);
-    let mut path = Path::new();
-    let mut max_dist = 0;
-    for dir in dirs {
-        path.short_step(&dir);
-        max_dist = max_dist.max(path.dist());
-    }
-    max_dist.to_string()
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day11::part2(&puzzle::read::<Day11>(s)?)
+}
+
+/// Generates a random path, drifting away in a random direction
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let directions = ["n", "ne", "se", "s", "sw", "nw"];
+    let drift = *rng.choose(&directions);
+    let path: Vec<&str> = (0..8000 * scale)
+        .map(|_| {
+            if rng.one_in(5) {
+                drift
+            } else {
+                *rng.choose(&directions)
+            }
+        })
+        .collect();
+    path.join(",")
 }
@@ -0,0 +1,142 @@
+use std::collections::BTreeSet;
+use generate::Rng;
+use graph::Graph;
+use puzzle::{self, Day, Puzzle};
+use scan::{self, Scanner};
+use {Answer, SolveError};
+
+/// Day 12: Digital Plumber
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 12,
+    title: "Digital Plumber",
+    description: "Size of the group of programs connected to 0, then the number of groups",
+    input: "input/2017/day12",
+    parts: &[one, two],
+    params: &[],
+    generate,
+    checks: &[],
+    parse: puzzle::parse::<Day12>,
+    solve: puzzle::solve::<Day12>,
+};
+
+/// Parses the number of a program, which must have its own definition
+fn parse_program(l: &mut Scanner, programs: usize) -> Result<usize, SolveError> {
+    l.skip_whitespace();
+    let column = l.column();
+    match l.number()? {
+        n if n < programs => Ok(n),
+        n => Err(l.error_at(column, format!("Program {} has no definition", n))),
+    }
+}
+
+/// Parses a pipe definition: a program, then the programs it is connected to
+fn parse_pipe(l: &mut Scanner, programs: usize) -> Result<(usize, Vec<usize>), SolveError> {
+    let first = parse_program(l, programs)?;
+    l.expect("<->")?;
+    let neighbors = l.separated(",", |l| parse_program(l, programs))?;
+    Ok((first, neighbors))
+}
+
+/// Parses a list of pipe definitions into the graph of the programs,
+/// every program having its own definition
+fn parse_pipes(s: &str) -> Result<Graph, SolveError> {
+    let programs = s.lines().count();
+    if programs == 0 {
+        return Err(SolveError::InvalidInput(String::from(
+            "There should be at least one program",
+        )));
+    }
+    let mut pipes = Graph::new(programs);
+    for (first, neighbors) in scan::lines(s, |l| parse_pipe(l, programs))? {
+        for n in neighbors {
+            pipes.add_edge(first, n);
+        }
+    }
+    Ok(pipes)
+}
+
+/// Solver of the pipes, working on the neighbors of each program
+pub struct Day12;
+
+impl Day for Day12 {
+    type Input = Graph;
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        parse_pipes(s)
+    }
+
+    fn part1(pipes: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(pipes.bfs(0).len().into())
+    }
+
+    fn part2(pipes: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(pipes.components().len().into())
+    }
+}
+
+/// Calculates the number of nodes connected to 0
+/// in the provided list of pipes
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day12::one;
+/// let pipes = "\
+/// 0 <-> 2
+/// 1 <-> 1
+/// 2 <-> 0, 3, 4
+/// 3 <-> 2, 4
+/// 4 <-> 2, 3, 6
+/// 5 <-> 6
+/// 6 <-> 4, 5";
+/// assert_eq!("6", one(pipes).unwrap());
+/// assert!(one("").is_err());
+/// ```
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day12::part1(&puzzle::read::<Day12>(s)?)
+}
+
+/// Calculates the number of interconnected groups
+/// in the provided list of pipes
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day12::two;
+/// let pipes = "\
+/// 0 <-> 2
+/// 1 <-> 1
+/// 2 <-> 0, 3, 4
+/// 3 <-> 2, 4
+/// 4 <-> 2, 3, 6
+/// 5 <-> 6
+/// 6 <-> 4, 5";
+/// assert_eq!("2", two(pipes).unwrap());
+/// ```
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day12::part2(&puzzle::read::<Day12>(s)?)
+}
+
+/// Generates random pipes between the programs, working both ways
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let n = 2000 * scale;
+    let mut neighbors: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); n];
+    for i in 0..n {
+        for _ in 0..rng.range(0, 2) {
+            let j = rng.below(n);
+            neighbors[i].insert(j);
+            neighbors[j].insert(i);
+        }
+    }
+    let pipes: Vec<String> = neighbors
+        .iter_mut()
+        .enumerate()
+        .map(|(i, others)| {
+            if others.is_empty() {
+                others.insert(i);
+            }
+            let others: Vec<String> = others.iter().map(|n| n.to_string()).collect();
+            format!("{} <-> {}", i, others.join(", "))
+        })
+        .collect();
+    pipes.join("\n")
+}
@@ -0,0 +1,154 @@
+use cancel;
+use generate::Rng;
+use puzzle::{self, Day, Puzzle};
+use scan::{self, Scanner};
+use {Answer, SolveError};
+
+/// Day 13: Packet Scanners
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 13,
+    title: "Packet Scanners",
+    description: "Severity of a trip through the firewall, then the shortest delay to cross it uncaught",
+    input: "input/2017/day13",
+    parts: &[one, two],
+    params: &[],
+    generate,
+    checks: &[],
+    parse: puzzle::parse::<Day13>,
+    solve: puzzle::solve::<Day13>,
+};
+
+/// Parses a single line describing a scanner
+fn parse_scanner(l: &mut Scanner) -> Result<(usize, usize), SolveError> {
+    let depth = l.number()?;
+    l.expect(":")?;
+    Ok((depth, l.number()?))
+}
+
+/// Parses the range of the scanner of each depth
+/// provided in the input
+fn parse_scanners(s: &str) -> Result<Vec<(usize, usize)>, SolveError> {
+    scan::lines(s, parse_scanner)
+}
+
+/// Calculates the penalty at the depth
+/// if the packet leaves at t=offset.
+/// A scanner with a range of 1 never moves, and catches every packet.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day13::penalty;
+/// assert_eq!(true, penalty(0, 3, 0));
+/// assert_eq!(false, penalty(1, 2, 0));
+/// assert_eq!(false, penalty(4, 4, 0));
+/// assert_eq!(true, penalty(6, 4, 0));
+/// assert_eq!(true, penalty(3, 1, 5));
+/// assert_eq!(false, penalty(0, 0, 0));
+/// ```
+#[inline]
+pub fn penalty(depth: usize, range: usize, offset: usize) -> bool {
+    match range {
+        0 => false,
+        1 => true,
+        _ => (depth + offset).is_multiple_of(2 * (range - 1)),
+    }
+}
+
+/// Solver of the firewall, working on the depth and range of each scanner
+pub struct Day13;
+
+impl Day for Day13 {
+    type Input = Vec<(usize, usize)>;
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        parse_scanners(s)
+    }
+
+    fn part1(scanners: &Self::Input) -> Result<Answer, SolveError> {
+        let severity: usize = scanners
+            .iter()
+            .filter(|&&(d, r)| penalty(d, r, 0))
+            .map(|&(d, r)| d * r)
+            .sum();
+        Ok(severity.into())
+    }
+
+    fn part2(scanners: &Self::Input) -> Result<Answer, SolveError> {
+        if let Some(&(depth, _)) = scanners.iter().find(|&&(_, r)| r == 1) {
+            return Err(SolveError::NoSolution(format!(
+                "The scanner at depth {} never moves and always catches the packet",
+                depth
+            )));
+        }
+        for offset in 0.. {
+            if offset % 100_000 == 0 {
+                cancel::check()?;
+            }
+            if scanners.iter().all(|&(d, r)| !penalty(d, r, offset)) {
+                return Ok(offset.into());
+            }
+        }
+        Err(SolveError::NoSolution(String::from("The packet is always caught")))
+    }
+}
+
+/// Calculates the severity of a trip through the firewall
+/// when leaving at t=0
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day13::one;
+/// let s = "\
+/// 0: 3
+/// 1: 2
+/// 4: 4
+/// 6: 4";
+/// assert_eq!("24", one(s).unwrap());
+/// assert_eq!("3", one("0: 3\n3: 1\n4: 4").unwrap());
+/// ```
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day13::part1(&puzzle::read::<Day13>(s)?)
+}
+
+/// Finds the lowest number of picoseconds to wait
+/// before leaving in order not to be caught.
+///
+/// # Warning
+/// For now, the algorithm uses brute force.
+/// This can take a long time for large inputs.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day13::two;
+/// let s = "\
+/// 0: 3
+/// 1: 2
+/// 4: 4
+/// 6: 4";
+/// assert_eq!("10", two(s).unwrap());
+/// assert!(two("0: 1").is_err());
+/// assert!(two("0: 3\n2: 1").is_err());
+/// ```
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day13::part2(&puzzle::read::<Day13>(s)?)
+}
+
+/// Generates random layers of the firewall,
+/// always leaving a delay for the packet to pass through them
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let delay = rng.range(10_000, 4_000_000);
+    let mut layers = Vec::new();
+    let mut depth = 0;
+    while layers.len() < 43 * scale {
+        let range = loop {
+            let range = rng.range(2, 20);
+            if (delay + depth) % (2 * (range - 1)) != 0 {
+                break range;
+            }
+        };
+        layers.push(format!("{}: {}", depth, range));
+        depth += rng.range(1, 3);
+    }
+    layers.join("\n")
+}
@@ -0,0 +1,118 @@
+use generate::Rng;
+use graph::Graph;
+use grid::{Dense, Dir, Point};
+use super::day10::knot_hash;
+use puzzle::{self, Day, Puzzle};
+use scan;
+use {Answer, SolveError};
+
+/// Day 14: Disk Defragmentation
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 14,
+    title: "Disk Defragmentation",
+    description: "Count the used squares of a disk built from knot hashes, then its regions",
+    input: "input/2017/day14",
+    parts: &[one, two],
+    params: &[],
+    generate,
+    checks: &[],
+    parse: puzzle::parse::<Day14>,
+    solve: puzzle::solve::<Day14>,
+};
+
+/// Converts a byte to a vector of its bits
+fn to_bits(x: u8) -> Vec<bool> {
+    [128, 64, 32, 16, 8, 4, 2, 1]
+        .iter()
+        .map(|b| x & b > 0)
+        .collect()
+}
+
+/// Creates the disk grid from the key, a cell being true if it is used.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day14::grid;
+/// use advent_of_code::grid::Point;
+/// let g = grid("flqrgnkx");
+/// let used = [(0,0),(1,0),(3,0),(1,1),(3,1),(0,3),(2,3)];
+/// assert!(used.iter().all(|&(x, y)| g[Point::new(x, y)]));
+/// let free = [(2,0),(0,1),(2,1),(0,2),(1,2),(2,2),(3,2),(1,3),(3,3)];
+/// assert!(free.iter().all(|&(x, y)| !g[Point::new(x, y)]));
+/// ```
+pub fn grid(key: &str) -> Dense<bool> {
+    let mut grid = Dense::new(128, 128, false);
+    for y in 0..128 {
+        let row = knot_hash(&format!("{}-{}", key, y))
+            .into_iter()
+            .flat_map(to_bits);
+        for (x, used) in row.enumerate() {
+            grid[Point::new(x as i64, y)] = used;
+        }
+    }
+    grid
+}
+
+/// Solver of the disk, working on the used cells of its grid
+pub struct Day14;
+
+impl Day for Day14 {
+    type Input = Dense<bool>;
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        scan::line(s, |l| l.name()).map(grid)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(grid.points().filter(|&p| grid[p]).count().into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer, SolveError> {
+        let regions = links(grid)
+            .components()
+            .into_iter()
+            .filter(|region| grid[cell(region[0])])
+            .count();
+        Ok(regions.into())
+    }
+}
+
+/// Counts the number of used cells in the grid
+pub fn one(key: &str) -> Result<Answer, SolveError> {
+    Day14::part1(&puzzle::read::<Day14>(key)?)
+}
+
+/// Node of the graph of the disk standing for the cell
+fn node(p: Point) -> usize {
+    (p.y * 128 + p.x) as usize
+}
+
+/// Cell of the disk a node of its graph stands for
+fn cell(node: usize) -> Point {
+    Point::new(node as i64 % 128, node as i64 / 128)
+}
+
+/// Graph linking every used cell to the used cells next to it,
+/// so that each region of used cells is a component
+fn links(grid: &Dense<bool>) -> Graph {
+    let mut links = Graph::new(grid.width() * grid.height());
+    for p in grid.points().filter(|&p| grid[p]) {
+        for &n in &[p + Dir::Right, p + Dir::Down] {
+            if grid.get(n) == Some(&true) {
+                links.add_undirected_edge(node(p), node(n));
+            }
+        }
+    }
+    links
+}
+
+/// Counts the contiguous groups in the drive
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day14::part2(&puzzle::read::<Day14>(s)?)
+}
+
+/// Generates a random key
+pub fn generate(rng: &mut Rng, _scale: usize) -> String {
+    rng.word(8)
+}
@@ -0,0 +1,151 @@
+use cancel;
+use generate::Rng;
+use param::{self, Param};
+use puzzle::{self, Day, Puzzle};
+use scan::{self, Scanner};
+use {Answer, SolveError};
+
+/// Day 15: Dueling Generators
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 15,
+    title: "Dueling Generators",
+    description: "Count the pairs of values whose lowest 16 bits match between two generators",
+    input: "input/2017/day15",
+    parts: &[one, two],
+    params: &[PAIRS],
+    generate,
+    checks: &[],
+    parse: puzzle::parse::<Day15>,
+    solve: puzzle::solve::<Day15>,
+};
+
+/// Number of pairs compared by the judge
+pub const PAIRS: Param = Param {
+    name: "pairs",
+    description: "number of pairs of values compared by the judge",
+    defaults: &[40_000_000, 5_000_000],
+};
+
+/// Retrieves the starting value of a generator from its description,
+/// which can be reduced to the value alone
+fn parse_value(l: &mut Scanner) -> Result<usize, SolveError> {
+    if l.eat("Generator") {
+        l.name()?;
+        l.expect("starts with")?;
+    }
+    l.number()
+}
+
+/// Retrieves the starting values of the generators
+fn parse_input(s: &str) -> Result<(usize, usize), SolveError> {
+    let v = scan::lines(s, parse_value)?;
+    if v.len() != 2 {
+        Err(SolveError::InvalidInput(String::from(
+            "Expected the starting values of both generators",
+        )))?
+    }
+    Ok((v[0], v[1]))
+}
+
+/// A sequence generator
+struct Generator {
+    factor: usize,
+    value: usize,
+}
+
+impl Generator {
+    fn new(factor: usize, value: usize) -> Generator {
+        Generator { factor, value }
+    }
+}
+
+impl Iterator for Generator {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.value = (self.value * self.factor) % 2_147_483_647;
+        Some(self.value)
+    }
+}
+
+/// Compares the last 16 bits of two numbers,
+/// returning true if they match.
+fn judge(a: usize, b: usize) -> bool {
+    (a ^ b).trailing_zeros() >= 16
+}
+
+/// Counts the pairs judged to match,
+/// stopping early if the solver is cancelled
+fn count_matches<I: Iterator<Item = (usize, usize)>>(pairs: I) -> Result<usize, SolveError> {
+    let mut count = 0;
+    for (i, (a, b)) in pairs.enumerate() {
+        if i % 1_000_000 == 0 {
+            cancel::check()?;
+        }
+        if judge(a, b) {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+/// Solver of the generators, working on their starting values
+pub struct Day15;
+
+impl Day for Day15 {
+    type Input = (usize, usize);
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        parse_input(s)
+    }
+
+    fn part1(&(value_a, value_b): &Self::Input) -> Result<Answer, SolveError> {
+        let a = Generator::new(16_807, value_a);
+        let b = Generator::new(48_271, value_b);
+
+        count_matches(a.zip(b).take(param::get(&PAIRS, 1)?)).map(Answer::from)
+    }
+
+    fn part2(&(value_a, value_b): &Self::Input) -> Result<Answer, SolveError> {
+        let a = Generator::new(16_807, value_a).filter(|x| x % 4 == 0);
+        let b = Generator::new(48_271, value_b).filter(|x| x % 8 == 0);
+
+        count_matches(a.zip(b).take(param::get(&PAIRS, 2)?)).map(Answer::from)
+    }
+}
+
+/// Counts the number of matching pairs
+/// in the first 40 million pairs generated by A and B
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day15::one;
+/// let s = "65\n8921";
+/// assert_eq!("588", one(s).unwrap());
+/// ```
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day15::part1(&puzzle::read::<Day15>(s)?)
+}
+
+/// Counts the number of matching pairs
+/// in the first 40 million pairs generated by A and B
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day15::two;
+/// let s = "65\n8921";
+/// assert_eq!("309", two(s).unwrap());
+/// ```
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day15::part2(&puzzle::read::<Day15>(s)?)
+}
+
+/// Generates random starting values for the generators
+pub fn generate(rng: &mut Rng, _scale: usize) -> String {
+    format!(
+        "Generator A starts with {}\nGenerator B starts with {}",
+        rng.range(1, 999),
+        rng.range(1, 999)
+    )
+}
@@ -0,0 +1,332 @@
+use self::Move::*;
+use cancel;
+use crosscheck::{self, Check};
+use generate::Rng;
+use param::{self, Param};
+use permutation::Permutation;
+use puzzle::{self, Day, Puzzle};
+use scan::{self, Scanner};
+use {Answer, SolveError};
+
+/// Day 16: Permutation Promenade
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 16,
+    title: "Permutation Promenade",
+    description: "Order of the programs after their dance, then after a billion dances",
+    input: "input/2017/day16",
+    parts: &[one, two],
+    params: &[DANCERS, DANCES],
+    generate,
+    checks: &[CHECK],
+    parse: puzzle::parse::<Day16>,
+    solve: puzzle::solve::<Day16>,
+};
+
+/// Number of dancers
+pub const DANCERS: Param = Param {
+    name: "dancers",
+    description: "number of dancers, named after the first letters",
+    defaults: &[16],
+};
+
+/// Number of dances of the second part
+pub const DANCES: Param = Param {
+    name: "dances",
+    description: "number of dances of the second part",
+    defaults: &[1_000_000_000],
+};
+
+/// Repeats the dances one by one, to cross-check the repeated permutations
+/// on a few hundred dances
+pub const CHECK: Check = Check {
+    part: 2,
+    reference: two_brute_force,
+    generate,
+    params: &[("dances", 300)],
+    shrink,
+};
+
+/// Number of the dancer named after the letter, starting from 0 for `a`
+fn label(name: char) -> Option<usize> {
+    (name as u32).checked_sub('a' as u32).map(|l| l as usize)
+}
+
+/// The dancers as they are ordered at the beginning,
+/// checking that the moves only refer to them
+fn initial_dancers(n: usize, moves: &[Move<char>]) -> Result<Vec<char>, SolveError> {
+    if n == 0 || n > 26 {
+        return Err(SolveError::InvalidInput(format!(
+            "There should be between 1 and 26 dancers, not {}",
+            n
+        )));
+    }
+    let valid = |m: &Move<char>| match *m {
+        Spin(s) => s <= n,
+        Exchange(a, b) => a < n && b < n,
+        Partner(a, b) => [a, b].iter().all(|&d| label(d).is_some_and(|l| l < n)),
+    };
+    if !moves.iter().all(valid) {
+        return Err(SolveError::InvalidInput(format!(
+            "The moves refer to positions or names beyond the {} dancers",
+            n
+        )));
+    }
+    Ok((b'a'..).take(n).map(char::from).collect())
+}
+
+/// Describes a move to dance
+pub enum Move<T> {
+    Spin(usize),
+    Exchange(usize, usize),
+    Partner(T, T),
+}
+
+/// Trait allowing a collection to simulate dancers
+pub trait Dance<T> {
+    /// Moves the last n elements at the front
+    /// without changing their order
+    fn spin(&mut self, n: usize);
+
+    /// Exchanges the dancers at positions a and b
+    fn exchange(&mut self, a: usize, b: usize);
+
+    /// Exchanges the dancers named a and b
+    fn partner(&mut self, a: &T, b: &T);
+
+    /// Performs a move
+    fn perform(&mut self, m: &Move<T>);
+}
+
+impl<T> Dance<T> for Vec<T>
+where
+    T: PartialEq,
+{
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::y2017::day16::Dance;
+    /// let mut v = vec!['a', 'b', 'c', 'd', 'e'];
+    /// v.spin(1);
+    /// assert_eq!("eabcd", v.into_iter().collect::<String>());
+    /// ```
+    fn spin(&mut self, n: usize) {
+        let size = self.len();
+        let mut end = self.split_off(size - n);
+        end.append(self);
+        *self = end;
+    }
+
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::y2017::day16::Dance;
+    /// let mut v = vec!['e', 'a', 'b', 'c', 'd'];
+    /// v.swap(3, 4);
+    /// assert_eq!("eabdc", v.into_iter().collect::<String>());
+    /// ```
+    fn exchange(&mut self, a: usize, b: usize) {
+        self.swap(a, b);
+    }
+
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::y2017::day16::Dance;
+    /// let mut v = vec!['e', 'a', 'b', 'd', 'c'];
+    /// v.partner(&'e', &'b');
+    /// assert_eq!("baedc", v.into_iter().collect::<String>());
+    /// ```
+    fn partner(&mut self, a: &T, b: &T) {
+        let pos_a = self.iter().position(|x| *x == *a).unwrap_or(0);
+        let pos_b = self.iter().position(|x| *x == *b).unwrap_or(0);
+        self.swap(pos_a, pos_b);
+    }
+
+    fn perform(&mut self, m: &Move<T>) {
+        match *m {
+            Spin(n) => self.spin(n),
+            Exchange(a, b) => self.exchange(a, b),
+            Partner(ref a, ref b) => self.partner(a, b),
+        }
+    }
+}
+
+/// Parses a single move
+fn parse_move(l: &mut Scanner) -> Result<Move<char>, SolveError> {
+    l.skip_whitespace();
+    let column = l.column();
+    match l.char()? {
+        's' => Ok(Spin(l.number()?)),
+        'x' => {
+            let a = l.number()?;
+            l.expect("/")?;
+            Ok(Exchange(a, l.number()?))
+        }
+        'p' => {
+            let a = l.letter()?;
+            l.expect("/")?;
+            Ok(Partner(a, l.letter()?))
+        }
+        op => Err(l.error_at(column, format!("Unknown operation {}", op))),
+    }
+}
+
+/// Parses a list of comma-separated moves
+fn parse(s: &str) -> Result<Vec<Move<char>>, SolveError> {
+    scan::line(s, |l| l.separated(",", parse_move))
+}
+
+/// Solver of the dance, working on the list of moves
+pub struct Day16;
+
+impl Day for Day16 {
+    type Input = Vec<Move<char>>;
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        parse(s)
+    }
+
+    fn part1(moves: &Self::Input) -> Result<Answer, SolveError> {
+        let dancers = initial_dancers(param::get(&DANCERS, 1)?, moves)?;
+        let dance = Choreography::new(dancers.len(), moves);
+        Ok(Answer::Text(dance.dancers()))
+    }
+
+    fn part2(moves: &Self::Input) -> Result<Answer, SolveError> {
+        let dancers = initial_dancers(param::get(&DANCERS, 2)?, moves)?;
+        let dances = param::get(&DANCES, 2)?;
+        let dance = Choreography::new(dancers.len(), moves).repeat(dances);
+        Ok(Answer::Text(dance.dancers()))
+    }
+}
+
+/// Performs a list of moves and returns the final ordering of dancers
+///
+/// # Examples
+/// ```
+/// use advent_of_code::param;
+/// use advent_of_code::y2017::day16::one;
+/// param::set_current(vec![(String::from("dancers"), 5)].into_iter().collect());
+/// assert_eq!("baedc", one("s1,x3/4,pe/b").unwrap());
+/// assert!(one("s1,x3/4,pe/f").is_err());
+/// ```
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day16::part1(&puzzle::read::<Day16>(s)?)
+}
+
+/// A dance reduced to the permutation of the positions made by the spins and exchanges,
+/// and to the permutation of the names made by the partners.
+/// Both can be applied separately, since the partners do not depend on the positions
+/// of the dancers, nor the spins and exchanges on their names.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Choreography {
+    pub positions: Permutation,
+    pub names: Permutation,
+}
+
+impl Choreography {
+    /// Reduces the moves of a dance of `n` dancers,
+    /// which must only refer to them
+    pub fn new(n: usize, moves: &[Move<char>]) -> Self {
+        let mut positions = Permutation::identity(n);
+        let mut names = Permutation::identity(n);
+        for m in moves {
+            match *m {
+                Spin(s) => positions = positions.then(&Permutation::rotation(n, s)),
+                Exchange(a, b) => positions = positions.then(&Permutation::transposition(n, a, b)),
+                Partner(a, b) => {
+                    let (a, b) = (label(a).unwrap_or(0), label(b).unwrap_or(0));
+                    names = names.then(&Permutation::transposition(n, a, b));
+                }
+            }
+        }
+        Choreography { positions, names }
+    }
+
+    /// The dance repeated `n` times in a row
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::y2017::day16::{Choreography, Dance, Move};
+    /// let moves = [Move::Spin(1), Move::Exchange(3, 4), Move::Partner('e', 'b')];
+    /// let dance = Choreography::new(5, &moves);
+    /// let mut dancers: Vec<char> = "abcde".chars().collect();
+    /// for n in 0..30 {
+    ///     assert_eq!(dancers.iter().collect::<String>(), dance.repeat(n).dancers());
+    ///     moves.iter().for_each(|m| dancers.perform(m));
+    /// }
+    /// assert_eq!("abcde", dance.repeat(1 << 40).dancers());
+    /// ```
+    pub fn repeat(&self, n: u64) -> Self {
+        Choreography {
+            positions: self.positions.pow(n),
+            names: self.names.pow(n),
+        }
+    }
+
+    /// Order of the dancers named after the first letters
+    /// once the dance is performed from the alphabetical order
+    pub fn dancers(&self) -> String {
+        self.positions
+            .permute(self.names.images())
+            .into_iter()
+            .map(|l| char::from(b'a' + l as u8))
+            .collect()
+    }
+}
+
+/// Performs a list of moves as many times as there are dances,
+/// 1 billion by default, and returns the result
+///
+/// # Examples
+/// ```
+/// use advent_of_code::param;
+/// use advent_of_code::y2017::day16::two;
+/// let values = vec![(String::from("dancers"), 5), (String::from("dances"), 2)];
+/// param::set_current(values.into_iter().collect());
+/// assert_eq!("ceadb", two("s1,x3/4,pe/b").unwrap());
+/// param::set_current(vec![(String::from("dancers"), 5)].into_iter().collect());
+/// assert_eq!("abcde", two("s1,x3/4,pe/b").unwrap());
+/// ```
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day16::part2(&puzzle::read::<Day16>(s)?)
+}
+
+/// Performs a list of moves as many times as there are dances,
+/// one move at a time
+pub fn two_brute_force(s: &str) -> Result<Answer, SolveError> {
+    let moves = puzzle::read::<Day16>(s)?;
+    let mut dancers = initial_dancers(param::get(&DANCERS, 2)?, &moves)?;
+    let dances: usize = param::get(&DANCES, 2)?;
+    for i in 0..dances {
+        if i % 1000 == 0 {
+            cancel::check()?;
+        }
+        for m in &moves {
+            dancers.perform(m);
+        }
+    }
+    Ok(Answer::Text(dancers.into_iter().collect()))
+}
+
+/// Smaller lists of moves
+fn shrink(s: &str) -> Vec<String> {
+    crosscheck::shrink_items(s, ",")
+}
+
+/// Generates random moves of the 16 dancers
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let moves: Vec<String> = (0..10_000 * scale)
+        .map(|_| {
+            let a = rng.below(16);
+            let b = (a + 1 + rng.below(15)) % 16;
+            match rng.below(3) {
+                0 => format!("s{}", a + 1),
+                1 => format!("x{}/{}", a, b),
+                _ => format!("p{}/{}", (b'a' + a as u8) as char, (b'a' + b as u8) as char),
+            }
+        })
+        .collect();
+    moves.join(",")
+}
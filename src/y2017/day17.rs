@@ -0,0 +1,128 @@
+use cancel;
+use crosscheck::{self, Check};
+use generate::Rng;
+use param::{self, Param};
+use progress;
+use puzzle::{self, Day, Puzzle};
+use scan;
+use {Answer, SolveError};
+
+/// Day 17: Spinlock
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 17,
+    title: "Spinlock",
+    description: "Value following 2017 in the spinlock buffer, then the value following 0 after 50 million insertions",
+    input: "input/2017/day17",
+    parts: &[one, two],
+    params: &[INSERTIONS],
+    generate,
+    checks: &[CHECK],
+    parse: puzzle::parse::<Day17>,
+    solve: puzzle::solve::<Day17>,
+};
+
+/// Number of values inserted in the buffer
+pub const INSERTIONS: Param = Param {
+    name: "insertions",
+    description: "number of values inserted in the buffer",
+    defaults: &[2017, 50_000_000],
+};
+
+/// Simulates the whole buffer, to cross-check the second part
+/// on a few thousand insertions
+pub const CHECK: Check = Check {
+    part: 2,
+    reference: two_brute_force,
+    generate,
+    params: &[("insertions", 3000)],
+    shrink: crosscheck::shrink_number,
+};
+
+/// Inserts the values in the circular buffer,
+/// returning the buffer and the position of the last inserted value
+fn spin(steps: usize, insertions: usize) -> (Vec<usize>, usize) {
+    let mut v = vec![0];
+    let mut i = 0;
+    for j in 1..insertions + 1 {
+        i = (i + steps) % j;
+        v.insert(i + 1, j);
+        i += 1;
+    }
+    (v, i)
+}
+
+/// Solver of the spinlock, working on its number of steps
+pub struct Day17;
+
+impl Day for Day17 {
+    type Input = usize;
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        scan::line(s, |l| l.number())
+    }
+
+    fn part1(&n: &Self::Input) -> Result<Answer, SolveError> {
+        let (v, i) = spin(n, param::get(&INSERTIONS, 1)?);
+        Ok(v[(i + 1) % v.len()].into())
+    }
+
+    fn part2(&n: &Self::Input) -> Result<Answer, SolveError> {
+        let insertions: usize = param::get(&INSERTIONS, 2)?;
+        let mut v1 = 0;
+        let mut i = 0;
+        for j in 1..insertions + 1 {
+            i = (i + n) % j;
+            if i == 0 {
+                v1 = j;
+            }
+            i += 1;
+            if j % 1_000_000 == 0 {
+                cancel::check()?;
+                progress::report(j as u64, Some(insertions as u64));
+            }
+        }
+        Ok(v1.into())
+    }
+}
+
+/// Simulates the circular buffer
+/// and finds the number positioned
+/// after the 2017th inserted number.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day17::one;
+/// assert_eq!("638", one("3").unwrap());
+/// ```
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day17::part1(&puzzle::read::<Day17>(s)?)
+}
+
+/// Instead of simulating the whole buffer,
+/// only simulates the first two elements
+/// and saves only the relevant data.
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day17::part2(&puzzle::read::<Day17>(s)?)
+}
+
+/// Simulates the whole buffer
+/// and finds the number positioned after 0,
+/// which always stays at the beginning of the buffer.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day17::two_brute_force;
+/// use advent_of_code::param;
+/// param::set_current(vec![(String::from("insertions"), 9)].into_iter().collect());
+/// assert_eq!("9", two_brute_force("3").unwrap());
+/// ```
+pub fn two_brute_force(s: &str) -> Result<Answer, SolveError> {
+    let (v, _) = spin(puzzle::read::<Day17>(s)?, param::get(&INSERTIONS, 2)?);
+    Ok(v[1 % v.len()].into())
+}
+
+/// Generates a random number of steps
+pub fn generate(rng: &mut Rng, _scale: usize) -> String {
+    rng.range(300, 400).to_string()
+}
@@ -0,0 +1,324 @@
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use self::Value::*;
+use self::Op::*;
+use self::ProgramIO::*;
+use cancel;
+use generate::Rng;
+use puzzle::{self, Day, Puzzle};
+use scan::{self, Scanner};
+use {Answer, SolveError};
+
+/// Day 18: Duet
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 18,
+    title: "Duet",
+    description: "Last sound recovered by a Duet program, then the values sent between two programs",
+    input: "input/2017/day18",
+    parts: &[one, two],
+    params: &[],
+    generate,
+    checks: &[],
+    parse: puzzle::parse::<Day18>,
+    solve: puzzle::solve::<Day18>,
+};
+
+/// Represents the right-hand value of an instruction.
+/// It either contains a direct value, as an Integer,
+/// or refers to a register's content.
+#[derive(Clone, Debug)]
+pub enum Value {
+    Integer(isize),
+    Register(char),
+}
+
+/// Parses a value description into a Value enum,
+/// depending on its nature.
+pub fn parse_val(l: &mut Scanner) -> Result<Value, SolveError> {
+    if l.at_number() {
+        l.number().map(Integer)
+    } else {
+        l.letter().map(Register)
+    }
+}
+
+/// Represents a Duet assembly instruction
+#[derive(Clone, Debug)]
+pub enum Op {
+    Snd(Value),
+    Set(char, Value),
+    Add(char, Value),
+    Mul(char, Value),
+    Mod(char, Value),
+    Rcv(char),
+    Jgz(Value, Value),
+}
+
+/// Parses an instruction description into an Op enum.
+pub fn parse_op(l: &mut Scanner) -> Result<Op, SolveError> {
+    l.skip_whitespace();
+    let column = l.column();
+    let op = match l.name()? {
+        "snd" => Snd(parse_val(l)?),
+        "set" => Set(l.letter()?, parse_val(l)?),
+        "add" => Add(l.letter()?, parse_val(l)?),
+        "mul" => Mul(l.letter()?, parse_val(l)?),
+        "mod" => Mod(l.letter()?, parse_val(l)?),
+        "rcv" => Rcv(l.letter()?),
+        "jgz" => Jgz(parse_val(l)?, parse_val(l)?),
+        op => return Err(l.error_at(column, format!("Unknown instruction {}", op))),
+    };
+    Ok(op)
+}
+
+/// Parses the complete program in Duet assembly
+/// into a vector of instructions
+pub fn parse(s: &str) -> Result<Vec<Op>, SolveError> {
+    scan::lines(s, parse_op)
+}
+
+/// Represents a running program
+struct Program {
+    regs: HashMap<char, isize>,
+    instructions: Vec<Op>,
+    pos: usize,
+    buffer: VecDeque<isize>,
+    /// Number of instructions executed so far
+    executed: u64,
+}
+
+/// Represents the external behavior of the program:
+/// whether it just sent a value,
+/// is waiting to receive one because its buffer is empty,
+/// or has left its code space and has terminated.
+pub enum ProgramIO {
+    Sent(isize),
+    Receive,
+    Terminate,
+}
+
+impl Program {
+    /// Create a new program with the corresponding id and code
+    fn new(id: isize, instructions: &[Op]) -> Self {
+        let mut regs = HashMap::new();
+        regs.insert('p', id);
+        Program {
+            regs,
+            instructions: instructions.to_vec(),
+            pos: 0,
+            buffer: VecDeque::new(),
+            executed: 0,
+        }
+    }
+
+    /// Retrieve the value of a right-hand member
+    /// in the context of the program, i.e. the value of the Integer
+    /// or the content of the Register
+    fn get(&self, v: &Value) -> isize {
+        match *v {
+            Integer(i) => i,
+            Register(c) => *self.regs.get(&c).unwrap_or(&0),
+        }
+    }
+
+    /// Executes the current instruction if possible,
+    /// and returns the emitted IO state if applicable
+    fn exec(&mut self) -> Option<ProgramIO> {
+        let op = match self.instructions.get(self.pos) {
+            Some(op) => op,
+            None => return Some(Terminate),
+        };
+        let mut next_address = self.pos + 1;
+        match *op {
+            Snd(ref v) => {
+                let sent = self.get(v);
+                self.pos = next_address;
+                return Some(Sent(sent));
+            }
+            Set(c, ref v) => {
+                let val = self.get(v);
+                self.regs.insert(c, val);
+            }
+            Add(c, ref v) => {
+                *self.regs.entry(c).or_insert(0) += self.get(v);
+            }
+            Mul(c, ref v) => {
+                *self.regs.entry(c).or_insert(0) *= self.get(v);
+            }
+            Mod(c, ref v) => {
+                *self.regs.entry(c).or_insert(0) %= self.get(v);
+            }
+            Rcv(c) => {
+                if let Some(val) = self.buffer.pop_front() {
+                    self.regs.insert(c, val);
+                } else {
+                    return Some(Receive);
+                }
+            }
+            Jgz(ref v, ref j) => {
+                if self.get(v) > 0 {
+                    next_address = (self.pos as isize + self.get(j)) as usize;
+                }
+            }
+        };
+        self.pos = next_address;
+        None
+    }
+}
+
+impl Iterator for Program {
+    type Item = Result<ProgramIO, SolveError>;
+    /// Executes the instructions until the program
+    /// either terminates, sends a value,
+    /// or waits for its buffer to be filled.
+    /// Fails if the run is cancelled meanwhile, as a malformed program may never stop.
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.executed += 1;
+            if self.executed.is_multiple_of(100_000) {
+                if let Err(e) = cancel::check() {
+                    return Some(Err(e));
+                }
+            }
+            match self.exec() {
+                Some(Terminate) => return None,
+                Some(x) => return Some(Ok(x)),
+                None => continue,
+            }
+        }
+    }
+}
+
+/// Solver of the duet, working on the instructions of the programs
+pub struct Day18;
+
+impl Day for Day18 {
+    type Input = Vec<Op>;
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        parse(s)
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<Answer, SolveError> {
+        let program = Program::new(0, instructions);
+        let mut sent = VecDeque::new();
+        for result in program {
+            match result? {
+                Sent(i) => sent.push_back(i),
+                Receive => return Ok(sent.pop_back().unwrap_or(0).into()),
+                Terminate => break,
+            }
+        }
+        Err(SolveError::NoSolution(String::from(
+            "Program terminated before deadlock",
+        )))
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<Answer, SolveError> {
+        let mut program0 = Program::new(0, instructions);
+        let mut program1 = Program::new(1, instructions);
+        let mut nb_sent = 0;
+
+        let mut rounds: u64 = 0;
+        let mut done = false;
+        while !done {
+            rounds += 1;
+            if rounds.is_multiple_of(10_000) {
+                cancel::check()?;
+            }
+            done = true;
+            if let Some(Sent(i)) = program0.next().transpose()? {
+                program1.buffer.push_back(i);
+                done = false;
+            }
+            if let Some(Sent(i)) = program1.next().transpose()? {
+                nb_sent += 1;
+                program0.buffer.push_back(i);
+                done = false;
+            }
+        }
+
+        Ok(nb_sent.into())
+    }
+}
+
+/// Launch a single program of id 0,
+/// and inspect the last value it sent before reaching a deadlock.
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day18::part1(&puzzle::read::<Day18>(s)?)
+}
+
+/// Launches two programs of id 0 and 1,
+/// and counts the number of values sent by program 1
+/// before a deadlock is reached.
+/// A program which never stops fails once its run is cancelled.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::cancel::{self, CancelToken};
+/// use advent_of_code::y2017::day18::two;
+/// assert_eq!("3", two("snd 1\nsnd 2\nsnd p\nrcv a\nrcv b\nrcv c\nrcv d").unwrap());
+/// let token = CancelToken::new();
+/// token.cancel();
+/// cancel::set_current(Some(token));
+/// assert!(two("set a 1\njgz a 0").is_err());
+/// ```
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day18::part2(&puzzle::read::<Day18>(s)?)
+}
+
+/// Generates a program of the shape of the puzzle inputs:
+/// both programs generate pseudo-random values from a seed,
+/// then sort them by sending them back and forth,
+/// until both wait for a value once they are sorted
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let count = 127 * scale;
+    format!(
+        "\
+set i 31
+set a 1
+mul p 17
+jgz p p
+mul a 2
+add i -1
+jgz i -2
+add a -1
+set i {count}
+set p {seed}
+mul p 8505
+mod p a
+mul p 129749
+add p 12345
+mod p a
+set b p
+mod b 10000
+snd b
+add i -1
+jgz i -9
+jgz a 3
+rcv b
+jgz b -1
+set f 0
+set i {last}
+rcv a
+rcv b
+set p a
+mul p -1
+add p b
+jgz p 4
+snd a
+set a b
+jgz 1 3
+snd b
+set f 1
+add i -1
+jgz i -11
+snd a
+jgz f -16
+jgz a -19",
+        count = count,
+        seed = rng.range(100, 999),
+        last = count - 1
+    )
+}
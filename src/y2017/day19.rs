@@ -0,0 +1,247 @@
+use self::Cell::*;
+use generate::Rng;
+use grid::{Dense, Dir, Point, Sparse};
+use input;
+use puzzle::{self, Day, Puzzle};
+use scan::{self, Scanner};
+use {Answer, SolveError};
+
+/// Day 19: A Series of Tubes
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 19,
+    title: "A Series of Tubes",
+    description: "Letters met by a packet following the routing diagram, then the number of steps it takes",
+    input: "input/2017/day19",
+    parts: &[one, two],
+    params: &[],
+    generate,
+    checks: &[],
+    parse: puzzle::parse::<Day19>,
+    solve: puzzle::solve::<Day19>,
+};
+
+/// A walkable cell of the grid
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Cell {
+    Ver,
+    Hor,
+    Cross,
+    Letter(char),
+}
+
+/// Parses the grid description character
+/// into the corresponding enum variant
+fn parse_cell(c: char) -> Option<Cell> {
+    match c {
+        '|' => Some(Ver),
+        '-' => Some(Hor),
+        '+' => Some(Cross),
+        x if x.is_alphabetic() => Some(Letter(x)),
+        _ => None,
+    }
+}
+
+/// Parses a row of the diagram into its walkable cells,
+/// along with the column of each one from 0
+fn parse_row(l: &mut Scanner) -> Result<Vec<(i64, Cell)>, SolveError> {
+    let mut cells = Vec::new();
+    while !l.at_end() {
+        let column = l.column();
+        let c = l.char()?;
+        match parse_cell(c) {
+            Some(cell) => cells.push((column as i64 - 1, cell)),
+            None => {
+                return Err(l.error_at(column, format!("Unexpected character {:?} in the diagram", c)))
+            }
+        }
+    }
+    Ok(cells)
+}
+
+/// Directions perpendicular to the one of the packet,
+/// rightwards or downwards first
+fn sides(dir: Dir) -> [Dir; 2] {
+    if dir.is_vertical() {
+        [Dir::Right, Dir::Left]
+    } else {
+        [Dir::Down, Dir::Up]
+    }
+}
+
+/// A packet on a grid, with its current position
+/// and direction of movement
+pub struct Packet<'a> {
+    pos: Point,
+    dir: Dir,
+    grid: &'a Sparse<Cell>,
+}
+
+impl<'a> Packet<'a> {
+    /// Initialises the packet on the grid
+    fn new(grid: &'a Sparse<Cell>) -> Result<Packet<'a>, SolveError> {
+        let (&point, _) = grid.iter().find(|&(p, _)| p.y == 0).ok_or_else(|| {
+            SolveError::InvalidInput(String::from("No entry point on row 0 of the grid"))
+        })?;
+        Ok(Packet {
+            pos: point + Dir::Up,
+            dir: Dir::Down,
+            grid,
+        })
+    }
+
+    /// Finds the new direction to take
+    /// when on a crossing: the side leading to a cell of the path,
+    /// which can be a line, a letter or another crossing,
+    /// but not a line parallel to the current direction
+    fn turn(&mut self) {
+        let [d1, d2] = sides(self.dir);
+        let parallel = if self.dir.is_vertical() { Ver } else { Hor };
+
+        let leads = |d: Dir| {
+            self.grid
+                .get(self.pos + d)
+                .is_some_and(|&cell| cell != parallel)
+        };
+
+        if leads(d1) {
+            self.dir = d1;
+        } else if leads(d2) {
+            self.dir = d2;
+        }
+    }
+}
+
+impl<'a> Iterator for Packet<'a> {
+    type Item = Option<char>;
+
+    /// Tries to continue walking in the same direction.
+    ///
+    /// - If it encounters nothing, it stops.
+    /// - If it encounters a letter, it returns it.
+    /// - If it encounters a crossing, it tries to turn.
+    /// - Else, it goes ahead and returns Some(None).
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pos = self.pos + self.dir;
+        if let Some(cell) = self.grid.get(self.pos) {
+            match *cell {
+                Letter(l) => return Some(Some(l)),
+                Cross => self.turn(),
+                _ => {}
+            };
+            Some(None)
+        } else {
+            None
+        }
+    }
+}
+
+/// Solver of the routing diagram, working on its walkable cells
+pub struct Day19;
+
+impl Day for Day19 {
+    type Input = Sparse<Cell>;
+
+    fn validate(s: &str) -> Result<(), SolveError> {
+        input::check_no_tabs(s)
+    }
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        let rows = scan::lines(s, parse_row)?;
+        Ok(rows
+            .into_iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.into_iter()
+                    .map(move |(x, cell)| (Point::new(x, y as i64), cell))
+            })
+            .collect())
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer, SolveError> {
+        let p = Packet::new(grid)?;
+        Ok(Answer::Text(p.into_iter().flatten().collect()))
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer, SolveError> {
+        let p = Packet::new(grid)?;
+        Ok(p.into_iter().count().into())
+    }
+}
+
+/// Recovers the letters found by the packet on its path.
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day19::part1(&puzzle::read::<Day19>(s)?)
+}
+
+/// Counts the number of steps to take to reach the end of the path.
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day19::part2(&puzzle::read::<Day19>(s)?)
+}
+
+/// Whether the path can go from a cell to the next one
+/// without running alongside itself
+fn free(cells: &Dense<char>, from: Point, to: Point) -> bool {
+    let inside = 1 <= to.x && to.x < cells.width() as i64 - 1 && 1 <= to.y
+        && to.y < cells.height() as i64 - 1;
+    inside && cells[to] == ' '
+        && to.neighbors4()
+            .iter()
+            .all(|&n| n == from || cells[n] == ' ')
+}
+
+/// Generates a random diagram, made of a single path entering from the top
+/// which never runs alongside itself, with letters along the way
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let size = 100 * scale + 1;
+    let mut cells = Dense::new(size, size, ' ');
+    let mut pos = Point::new(rng.range(1, size as i64 - 2), 0);
+    let mut dir = Dir::Down;
+    let mut path = vec![pos];
+    cells[pos] = '|';
+
+    for segment in 0..40 * scale {
+        if segment > 0 {
+            let sides = sides(dir);
+            let first = rng.below(2);
+            let side = [sides[first], sides[1 - first]]
+                .iter()
+                .cloned()
+                .find(|&d| free(&cells, pos, pos + d));
+            match side {
+                Some(side) => {
+                    cells[pos] = '+';
+                    dir = side;
+                }
+                None => break,
+            }
+        }
+        let length = rng.range(1, size as i64 / 4);
+        for _ in 0..length {
+            if !free(&cells, pos, pos + dir) {
+                break;
+            }
+            pos = pos + dir;
+            cells[pos] = if dir.is_vertical() { '|' } else { '-' };
+            path.push(pos);
+        }
+    }
+
+    // Letters are placed on straight cells, one of them at the end of the path
+    let mut straight: Vec<Point> = path[1..path.len() - 1]
+        .iter()
+        .cloned()
+        .filter(|&p| cells[p] != '+')
+        .collect();
+    rng.shuffle(&mut straight);
+    let mut letters: Vec<char> = (b'A'..b'Z' + 1).map(char::from).collect();
+    rng.shuffle(&mut letters);
+    let count = letters.len().min(straight.len() + 1).min(5 + 5 * scale);
+    straight.truncate(count - 1);
+    straight.push(path[path.len() - 1]);
+    for (p, letter) in straight.into_iter().zip(letters) {
+        cells[p] = letter;
+    }
+
+    cells.render(|&c| c)
+}
@@ -0,0 +1,134 @@
+use generate::Rng;
+use input;
+use puzzle::{self, Day, Puzzle};
+use scan;
+use {Answer, SolveError};
+
+/// Day 2: Corruption Checksum
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 2,
+    title: "Corruption Checksum",
+    description: "Checksum of a spreadsheet from the range of each row, then from its evenly divisible pair",
+    input: "input/2017/day2",
+    parts: &[one, two],
+    params: &[],
+    generate,
+    checks: &[],
+    parse: puzzle::parse::<Day2>,
+    solve: puzzle::solve::<Day2>,
+};
+
+/// Solver of the checksum, working on the rows of numbers of the spreadsheet
+pub struct Day2;
+
+impl Day for Day2 {
+    type Input = Vec<Vec<u32>>;
+
+    fn validate(s: &str) -> Result<(), SolveError> {
+        input::check_lines(s, |row| {
+            if row.trim().is_empty() {
+                Err("Empty row in the spreadsheet")
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        scan::lines(s, |row| row.repeat(|row| row.number()))
+    }
+
+    fn part1(rows: &Self::Input) -> Result<Answer, SolveError> {
+        let result: u32 = rows.iter().map(|row| checksum_one(row)).sum();
+        Ok(result.into())
+    }
+
+    fn part2(rows: &Self::Input) -> Result<Answer, SolveError> {
+        let result: u32 = rows.iter().map(|row| checksum_two(row)).sum();
+        Ok(result.into())
+    }
+}
+
+/// Calculates the difference between the maximum
+/// and minimum values in the list
+fn checksum_one(coll: &[u32]) -> u32 {
+    let min = coll.iter().min().unwrap_or(&0);
+    let max = coll.iter().max().unwrap_or(&0);
+    max - min
+}
+
+/// Calculates the checksum of the spreadsheet
+/// with the first definition
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day2::one;
+/// let spreadsheet = "\
+/// 5 1 9 5
+/// 7 5 3
+/// 2 4 6 8";
+/// assert_eq!("18", one(spreadsheet).unwrap());
+/// ```
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day2::part1(&puzzle::read::<Day2>(s)?)
+}
+
+/// Calculates the gcd of the only pair
+/// of non-coprime numbers in the list
+fn checksum_two(row: &[u32]) -> u32 {
+    // Unique elements sorted
+    let mut coll = row.to_vec();
+    coll.sort();
+    coll.dedup();
+
+    // Finding the only pair of non-coprime numbers in the list,
+    // and returning their gcd
+    for larger in coll.iter().rev() {
+        for lower in coll.iter().take_while(|&x| x < larger) {
+            if larger % lower == 0 {
+                return larger / lower;
+            }
+        }
+    }
+    0
+}
+
+/// Calculates the checksum of the spreadsheet
+/// with the second definition
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day2::two;
+/// let spreadsheet = "\
+/// 5 9 2 8
+/// 9 4 7 3
+/// 3 8 6 5";
+/// assert_eq!("9", two(spreadsheet).unwrap());
+/// ```
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day2::part2(&puzzle::read::<Day2>(s)?)
+}
+
+/// Generates a random spreadsheet, each row holding a single pair
+/// of numbers where one evenly divides the other
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut rows = Vec::new();
+    while rows.len() < 16 * scale {
+        let divisor = rng.range(2, 500);
+        let mut row = vec![divisor, divisor * rng.range(2, 9)];
+        while row.len() < 16 {
+            row.push(rng.range(100, 9999));
+        }
+        let pairs = (0..row.len())
+            .flat_map(|i| (0..row.len()).map(move |j| (i, j)))
+            .filter(|&(i, j)| i != j && row[j] % row[i] == 0)
+            .count();
+        if pairs == 1 {
+            rng.shuffle(&mut row);
+            let row: Vec<String> = row.iter().map(|n| n.to_string()).collect();
+            rows.push(row.join("\t"));
+        }
+    }
+    rows.join("\n")
+}
@@ -0,0 +1,227 @@
+use std::ops::{Add, Sub};
+use std::collections::HashMap;
+use generate::Rng;
+use puzzle::{self, Day, Puzzle};
+use scan::{self, Scanner};
+use {Answer, SolveError};
+
+/// Day 20: Particle Swarm
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 20,
+    title: "Particle Swarm",
+    description: "Particle staying the closest to the origin, then the particles left after all collisions",
+    input: "input/2017/day20",
+    parts: &[one, two],
+    params: &[],
+    generate,
+    checks: &[],
+    parse: puzzle::parse::<Day20>,
+    solve: puzzle::solve::<Day20>,
+};
+
+/// A point in 3D space
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point {
+    x: isize,
+    y: isize,
+    z: isize,
+}
+
+impl Point {
+    fn new(x: isize, y: isize, z: isize) -> Self {
+        Point { x, y, z }
+    }
+
+    /// Manhattan distance between the point and the origin (0,0,0),
+    /// aka the sum of the absolute values of each coordinate.
+    fn manhattan(&self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs() + self.z.unsigned_abs()
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+    fn add(self, other: Point) -> Point {
+        Point {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+    fn sub(self, other: Point) -> Point {
+        Point {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+/// A particle, described by its position, velocity and acceleration.
+#[derive(Clone, Copy)]
+pub struct Particle {
+    p: Point,
+    v: Point,
+    a: Point,
+}
+
+impl Particle {
+    fn new(p: Point, v: Point, a: Point) -> Self {
+        Particle { p, v, a }
+    }
+
+    /// Simulates a step of the particle in the simulation
+    fn step(&mut self) {
+        self.v = self.v + self.a;
+        self.p = self.p + self.v;
+    }
+}
+
+/// Parses a named vector of a particle, such as `p=<1,-2,3>`
+fn parse_vector(l: &mut Scanner, name: &str) -> Result<Point, SolveError> {
+    l.expect(name)?;
+    l.expect("=<")?;
+    let x = l.number()?;
+    l.expect(",")?;
+    let y = l.number()?;
+    l.expect(",")?;
+    let z = l.number()?;
+    l.expect(">")?;
+    Ok(Point::new(x, y, z))
+}
+
+/// Parses a particle definition
+fn parse_particle(l: &mut Scanner) -> Result<Particle, SolveError> {
+    let p = parse_vector(l, "p")?;
+    l.expect(",")?;
+    let v = parse_vector(l, "v")?;
+    l.expect(",")?;
+    let a = parse_vector(l, "a")?;
+    Ok(Particle::new(p, v, a))
+}
+
+/// Solver of the particle swarm, working on the initial particles
+pub struct Day20;
+
+impl Day for Day20 {
+    type Input = Vec<Particle>;
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        scan::lines(s, parse_particle)
+    }
+
+    fn part1(particles: &Self::Input) -> Result<Answer, SolveError> {
+        particles
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, x)| x.a.manhattan())
+            .map(|(i, _)| Answer::from(i))
+            .ok_or_else(|| SolveError::InvalidInput(String::from("No particle in the swarm")))
+    }
+
+    fn part2(particles: &Self::Input) -> Result<Answer, SolveError> {
+        let mut particles = particles.clone();
+        let mut nb_particles = particles.len();
+        let mut nb_iter_without_change = 0;
+        while nb_iter_without_change < 1000 {
+            particles = sim_step(particles);
+            if particles.len() < nb_particles {
+                nb_particles = particles.len();
+                nb_iter_without_change = 0;
+            } else {
+                nb_iter_without_change += 1;
+            }
+        }
+
+        Ok(nb_particles.into())
+    }
+}
+
+/// Finds the particle which will be the closest to the origin
+/// in the very long term.
+///
+/// The result is simply the particle
+/// with the lowest acceleration
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day20::part1(&puzzle::read::<Day20>(s)?)
+}
+
+/// Runs a step in the particle simulation,
+/// destroying colliding particles
+pub fn sim_step(particles: Vec<Particle>) -> Vec<Particle> {
+    let mut grid = HashMap::new();
+    for mut part in particles {
+        part.step();
+        grid.entry(part.p).or_insert_with(Vec::new).push(part);
+    }
+    grid.into_iter()
+        .filter(|(_, v)| v.len() == 1)
+        .map(|(_, v)| v[0])
+        .collect()
+}
+
+/// Runs the simulation until the number of particles
+/// seems to stop changing, aka when collisions stop happening
+/// for long enough.
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day20::part2(&puzzle::read::<Day20>(s)?)
+}
+
+/// Generates a random vector, each coordinate within the bound
+fn generate_vector(rng: &mut Rng, bound: i64) -> [i64; 3] {
+    [
+        rng.range(-bound, bound),
+        rng.range(-bound, bound),
+        rng.range(-bound, bound),
+    ]
+}
+
+/// Generates random particles, some of them colliding along the way,
+/// with a single one accelerating slower than all the others
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let n = 1000 * scale;
+    let mut particles: Vec<[[i64; 3]; 3]> = Vec::new();
+    while particles.len() < n {
+        if rng.one_in(10) {
+            // A group of particles meeting at the same position after t ticks
+            let t = rng.range(5, 40);
+            let meeting = generate_vector(rng, 3000);
+            for _ in 0..rng.range(2, 4) {
+                let (v, a) = (generate_vector(rng, 100), generate_vector(rng, 20));
+                let p = [0, 1, 2].map(|k| meeting[k] - v[k] * t - a[k] * t * (t + 1) / 2);
+                particles.push([p, v, a]);
+            }
+        } else {
+            let p = generate_vector(rng, 5000);
+            particles.push([p, generate_vector(rng, 100), generate_vector(rng, 20)]);
+        }
+    }
+    particles.truncate(n);
+
+    let slowest = rng.below(n);
+    for (i, particle) in particles.iter_mut().enumerate() {
+        let a = &mut particle[2];
+        if i == slowest {
+            *a = [0, 0, 0];
+            a[rng.below(3)] = *rng.choose(&[-1, 1]);
+        } else if a.iter().map(|x| x.abs()).sum::<i64>() < 2 {
+            a[0] += 2;
+        }
+    }
+
+    let lines: Vec<String> = particles
+        .iter()
+        .map(|&[p, v, a]| {
+            format!(
+                "p=<{},{},{}>, v=<{},{},{}>, a=<{},{},{}>",
+                p[0], p[1], p[2], v[0], v[1], v[2], a[0], a[1], a[2]
+            )
+        })
+        .collect();
+    lines.join("\n")
+}
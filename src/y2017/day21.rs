@@ -0,0 +1,301 @@
+use generate::Rng;
+use param::{self, Param};
+use puzzle::{self, Day, Puzzle};
+use scan::{self, Scanner};
+use {Answer, SolveError};
+
+/// Day 21: Fractal Art
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 21,
+    title: "Fractal Art",
+    description: "Count the pixels on after iterating the enhancement rules of a fractal",
+    input: "input/2017/day21",
+    parts: &[one, two],
+    params: &[ITERATIONS],
+    generate,
+    checks: &[],
+    parse: puzzle::parse::<Day21>,
+    solve: puzzle::solve::<Day21>,
+};
+
+/// A conversion rule, mapping all the symmetries of the original pattern
+/// to the corresponding resulting pattern.
+pub struct Rule {
+    size: usize,
+    patterns: Vec<Vec<bool>>,
+    result: Vec<bool>,
+}
+
+impl Rule {
+    /// Generates all the symmetries once and for all
+    fn new(pattern: &[Vec<bool>], result: Vec<bool>) -> Self {
+        let size = pattern.len();
+        // Correct order
+        let mut patterns = vec![pattern.iter().flatten().cloned().collect()];
+
+        // Horizontal flip
+        patterns.push(
+            pattern
+                .iter()
+                .cloned()
+                .flat_map(|x| x.into_iter().rev())
+                .collect(),
+        );
+
+        // Vertical flip
+        patterns.push(pattern.iter().cloned().rev().flatten().collect());
+
+        // Horizontal + vertical flip (= 180° rotation)
+        patterns.push(
+            pattern
+                .iter()
+                .cloned()
+                .rev()
+                .flat_map(|x| x.into_iter().rev())
+                .collect(),
+        );
+
+        // Transposed matrix, i.e. 90° rotation + flip
+        let transposed: Vec<Vec<bool>> = (0..size)
+            .map(|i| pattern.iter().map(|v| v[i]).collect())
+            .collect();
+        // Then we repeat the all flips on the transposed matrix
+
+        patterns.push(transposed.iter().flatten().cloned().collect());
+
+        patterns.push(
+            transposed
+                .iter()
+                .cloned()
+                .flat_map(|x| x.into_iter().rev())
+                .collect(),
+        );
+
+        patterns.push(transposed.iter().cloned().rev().flatten().collect());
+
+        patterns.push(
+            transposed
+                .iter()
+                .cloned()
+                .rev()
+                .flat_map(|x| x.into_iter().rev())
+                .collect(),
+        );
+
+        Rule {
+            size,
+            patterns,
+            result,
+        }
+    }
+
+    // Returns true if the rule corresponds to the square
+    fn match_rule(&self, square: &[bool]) -> bool {
+        if self.size.pow(2) != square.len() {
+            return false;
+        }
+        self.patterns.iter().any(|p| *p == square)
+    }
+}
+
+/// Parses a square of pixels, such as `.#./..#/###`,
+/// into its rows of pixels, true when on
+fn parse_square(l: &mut Scanner) -> Result<Vec<Vec<bool>>, SolveError> {
+    l.skip_whitespace();
+    let column = l.column();
+    let rows = l.separated("/", |l| {
+        l.token("a row of '.' and '#'", |c| c == '.' || c == '#')
+            .map(|row| row.chars().map(|c| c == '#').collect::<Vec<bool>>())
+    })?;
+    if rows.iter().any(|row| row.len() != rows.len()) {
+        return Err(l.error_at(column, "The pattern should be a square"));
+    }
+    Ok(rows)
+}
+
+/// Parses a rule definition, which turns a square of 2 or 3 pixels
+/// into a square one pixel larger
+fn parse_rule(l: &mut Scanner) -> Result<Rule, SolveError> {
+    l.skip_whitespace();
+    let column = l.column();
+    let pattern = parse_square(l)?;
+    if pattern.len() != 2 && pattern.len() != 3 {
+        return Err(l.error_at(column, "The pattern should be 2 or 3 pixels wide"));
+    }
+    l.expect("=>")?;
+    l.skip_whitespace();
+    let column = l.column();
+    let result = parse_square(l)?;
+    if result.len() != pattern.len() + 1 {
+        return Err(l.error_at(
+            column,
+            format!("The result should be {} pixels wide", pattern.len() + 1),
+        ));
+    }
+    Ok(Rule::new(&pattern, result.into_iter().flatten().collect()))
+}
+
+/// Splits a fractal (2D matrix stored in a vector)
+/// into a vector of squares to be mapped to enhancement rules
+fn split(fractal: &[bool]) -> Vec<Vec<bool>> {
+    let size = (fractal.len() as f64).sqrt() as usize;
+    let size_cut = if size.is_multiple_of(2) { 2 } else { 3 };
+    let cuts = size / size_cut;
+
+    let mut result = vec![];
+    for y in 0..cuts {
+        for x in 0..cuts {
+            let mut v = vec![];
+            for j in 0..size_cut {
+                for i in 0..size_cut {
+                    v.push(fractal[(size_cut * y + j) * size + (size_cut * x + i)]);
+                }
+            }
+            result.push(v);
+        }
+    }
+
+    result
+}
+
+/// Merges a vector of squares into a new fractal,
+/// once the enhancements have been applied
+fn merge(squares: Vec<Vec<bool>>) -> Vec<bool> {
+    let cuts = (squares.len() as f64).sqrt() as usize;
+    let size_cut = (squares[0].len() as f64).sqrt() as usize;
+    let size = size_cut * cuts;
+
+    let mut result = vec![false; size * size];
+
+    for (n, square) in squares.into_iter().enumerate() {
+        let x = n % cuts;
+        let y = n / cuts;
+        for j in 0..size_cut {
+            for i in 0..size_cut {
+                result[(size_cut * y + j) * size + (size_cut * x + i)] = square[size_cut * j + i];
+            }
+        }
+    }
+
+    result
+}
+
+/// Iterates a step of the enhancement process
+/// on the provided fractal, and with the provided rules.
+fn iterate(rules: &[Rule], fractal: &[bool]) -> Result<Vec<bool>, SolveError> {
+    let squares = split(fractal);
+    let replaced = squares
+        .into_iter()
+        .map(|s| {
+            rules
+                .iter()
+                .find(|r| r.match_rule(&s))
+                .map(|r| r.result.clone())
+                .ok_or_else(|| {
+                    SolveError::InvalidInput(format!("No rule matches this cell pattern: {:?}", s))
+                })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(merge(replaced))
+}
+
+/// Number of times the fractal is enhanced
+pub const ITERATIONS: Param = Param {
+    name: "iterations",
+    description: "number of times the fractal is enhanced",
+    defaults: &[5, 18],
+};
+
+/// Counts the number of "on" pixels in the fractal
+/// after the given number of iterations
+fn count_on(rules: &[Rule], iterations: usize) -> Result<usize, SolveError> {
+    let mut fractal = vec![false, true, false, false, false, true, true, true, true];
+
+    for _ in 0..iterations {
+        fractal = iterate(rules, &fractal)?;
+    }
+
+    Ok(fractal.into_iter().filter(|&x| x).count())
+}
+
+/// Solver of the fractal art, working on the enhancement rules
+pub struct Day21;
+
+impl Day for Day21 {
+    type Input = Vec<Rule>;
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        scan::lines(s, parse_rule)
+    }
+
+    fn part1(rules: &Self::Input) -> Result<Answer, SolveError> {
+        count_on(rules, param::get(&ITERATIONS, 1)?).map(Answer::from)
+    }
+
+    fn part2(rules: &Self::Input) -> Result<Answer, SolveError> {
+        count_on(rules, param::get(&ITERATIONS, 2)?).map(Answer::from)
+    }
+}
+
+/// Counts the number of "on" pixels in the fractal
+/// after 5 iterations
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day21::part1(&puzzle::read::<Day21>(s)?)
+}
+
+/// Counts the number of "on" pixels in the fractal
+/// after 18 iterations
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day21::part2(&puzzle::read::<Day21>(s)?)
+}
+
+/// Rotations and flips of a square pattern of size n
+fn symmetries(pattern: &[bool], n: usize) -> Vec<Vec<bool>> {
+    let mut result = Vec::new();
+    let mut current = pattern.to_vec();
+    for _ in 0..4 {
+        let flipped = (0..n * n)
+            .map(|k| current[k / n * n + n - 1 - k % n])
+            .collect();
+        let rotated = (0..n * n)
+            .map(|k| current[(n - 1 - k % n) * n + k / n])
+            .collect();
+        result.push(current);
+        result.push(flipped);
+        current = rotated;
+    }
+    result
+}
+
+/// Formats a square pattern of size n as in the rules
+fn format_pattern(pattern: &[bool], n: usize) -> String {
+    let rows: Vec<String> = pattern
+        .chunks(n)
+        .map(|row| row.iter().map(|&on| if on { '#' } else { '.' }).collect())
+        .collect();
+    rows.join("/")
+}
+
+/// Generates random enhancement rules,
+/// a single one for every 2x2 and 3x3 pattern up to rotations and flips
+pub fn generate(rng: &mut Rng, _scale: usize) -> String {
+    let mut rules = Vec::new();
+    for n in 2..4 {
+        for bits in 0..1u32 << (n * n) {
+            let pattern: Vec<bool> = (0..n * n).map(|k| bits >> k & 1 == 1).collect();
+            // Only the smallest pattern of each class gets a rule
+            if symmetries(&pattern, n).iter().all(|s| *s >= pattern) {
+                let output: Vec<bool> = (0..(n + 1) * (n + 1)).map(|_| rng.one_in(2)).collect();
+                rules.push(format!(
+                    "{} => {}",
+                    format_pattern(&pattern, n),
+                    format_pattern(&output, n + 1)
+                ));
+            }
+        }
+    }
+    rng.shuffle(&mut rules);
+    rules.join("\n")
+}
@@ -0,0 +1,221 @@
+use self::Flag::{Clean, Flagged, Infected, Weakened};
+use cancel;
+use generate::Rng;
+use grid::{Dir, Point, Sparse, Turn};
+use param::{self, Param};
+use puzzle::{self, Day, Puzzle};
+use scan::{self, Scanner};
+use {Answer, SolveError};
+
+/// Day 22: Sporifica Virus
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 22,
+    title: "Sporifica Virus",
+    description: "Count the bursts of the virus carrier infecting a node, with the simple then the evolved rules",
+    input: "input/2017/day22",
+    parts: &[one, two],
+    params: &[BURSTS],
+    generate,
+    checks: &[],
+    parse: puzzle::parse::<Day22>,
+    solve: puzzle::solve::<Day22>,
+};
+
+/// The state of a cell of the cluster
+#[derive(Clone, Copy, PartialEq)]
+pub enum Flag {
+    Clean,
+    Weakened,
+    Infected,
+    Flagged,
+}
+
+/// The infected cells of the cluster, with the virus carrier
+#[derive(Clone)]
+pub struct Grid {
+    grid: Sparse<Flag>,
+    carrier: Point,
+    direction: Dir,
+    simple: bool,
+}
+
+/// Parses a row of the square grid of `size` cells,
+/// a cell being true if it is infected
+fn parse_row(l: &mut Scanner, size: usize) -> Result<Vec<bool>, SolveError> {
+    let row = l.take_while(|c| c == '.' || c == '#');
+    if !l.at_end() {
+        return Err(l.error("Cells should be either '.' or '#'"));
+    }
+    if row.len() != size {
+        return Err(l.error(format!("The grid should be square, with {} cells per row", size)));
+    }
+    Ok(row.chars().map(|c| c == '#').collect())
+}
+
+impl Grid {
+    /// Parses the cluster, whose carrier starts in the middle:
+    /// it must be a square with an odd number of rows
+    fn parse(s: &str, simple: bool) -> Result<Grid, SolveError> {
+        let size = s.lines().count();
+        if size == 0 {
+            return Err(SolveError::InvalidInput(String::from("The grid is empty")));
+        }
+        if size.is_multiple_of(2) {
+            return Err(SolveError::InvalidInput(format!(
+                "The grid should have an odd size to have a middle, not {}",
+                size
+            )));
+        }
+        let rows = scan::lines(s, |l| parse_row(l, size))?;
+        let grid = rows
+            .into_iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.into_iter()
+                    .enumerate()
+                    .filter(|&(_, infected)| infected)
+                    .map(move |(x, _)| (Point::new(x as i64, y as i64), Infected))
+            })
+            .collect();
+        let pos = ((size - 1) / 2) as i64;
+        Ok(Grid {
+            grid,
+            carrier: Point::new(pos, pos),
+            direction: Dir::Up,
+            simple,
+        })
+    }
+
+    /// Turns the carrier, changes the state of its cell and moves it forward
+    fn burst(&mut self, turn: Option<Turn>, flag: Flag) -> Flag {
+        if let Some(turn) = turn {
+            self.direction = self.direction.turn(turn);
+        }
+        self.grid.insert(self.carrier, flag);
+        self.carrier = self.carrier + self.direction;
+        flag
+    }
+
+    fn step(&mut self) -> Flag {
+        match self.grid.get(self.carrier).cloned().unwrap_or(Clean) {
+            Clean => self.burst(Some(Turn::Left), Weakened),
+            Weakened => self.burst(None, Infected),
+            Infected => self.burst(Some(Turn::Right), Flagged),
+            Flagged => self.burst(Some(Turn::Reverse), Clean),
+        }
+    }
+
+    fn simple_step(&mut self) -> Flag {
+        match self.grid.get(self.carrier).cloned().unwrap_or(Clean) {
+            Clean => self.burst(Some(Turn::Left), Infected),
+            Infected => self.burst(Some(Turn::Right), Clean),
+            x => x,
+        }
+    }
+}
+
+impl Iterator for Grid {
+    type Item = Flag;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = if self.simple {
+            self.simple_step()
+        } else {
+            self.step()
+        };
+        Some(x)
+    }
+}
+
+/// Number of bursts of activity of the virus carrier
+pub const BURSTS: Param = Param {
+    name: "bursts",
+    description: "number of bursts of activity of the virus carrier",
+    defaults: &[10_000, 10_000_000],
+};
+
+/// Counts the bursts leading to a cell becoming infected,
+/// stopping early if the solver is cancelled
+fn count_infections(grid: Grid, bursts: usize) -> Result<usize, SolveError> {
+    let mut count = 0;
+    for (i, flag) in grid.take(bursts).enumerate() {
+        if i % 1_000_000 == 0 {
+            cancel::check()?;
+        }
+        if flag == Infected {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+/// Solver of the virus, working on the initial state of the cluster
+pub struct Day22;
+
+impl Day for Day22 {
+    type Input = Grid;
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        Grid::parse(s, true)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer, SolveError> {
+        let mut grid = grid.clone();
+        grid.simple = true;
+        count_infections(grid, param::get(&BURSTS, 1)?).map(Answer::from)
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer, SolveError> {
+        let mut grid = grid.clone();
+        grid.simple = false;
+        count_infections(grid, param::get(&BURSTS, 2)?).map(Answer::from)
+    }
+}
+
+/// Counts the number of bursts among the first `10_000`
+/// that lead to a cell becoming infected.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day22::one;
+/// let s = "\
+/// ..#
+/// #..
+/// ...";
+/// assert_eq!("5587", one(s).unwrap());
+/// assert!(one("").is_err());
+/// assert!(one("..\n.#").is_err());
+/// ```
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day22::part1(&puzzle::read::<Day22>(s)?)
+}
+
+/// Counts the number of bursts among the first `10_000_000`
+/// that lead to a cell becoming infected.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day22::two;
+/// let s = "\
+/// ..#
+/// #..
+/// ...";
+/// assert_eq!("2511944", two(s).unwrap());
+/// ```
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day22::part2(&puzzle::read::<Day22>(s)?)
+}
+
+/// Generates a random square grid, of an odd size
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let size = 24 * scale + 1;
+    let rows: Vec<String> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.one_in(2) { '#' } else { '.' })
+                .collect()
+        })
+        .collect();
+    rows.join("\n")
+}
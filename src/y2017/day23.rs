@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+use self::Value::{Integer, Register};
+use self::Op::{Jnz, Mul, Set, Sub};
+use cancel;
+use crosscheck::Check;
+use generate::Rng;
+use progress;
+use puzzle::{self, Day, Puzzle};
+use scan::{self, Scanner};
+use {Answer, SolveError};
+
+/// Day 23: Coprocessor Conflagration
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 23,
+    title: "Coprocessor Conflagration",
+    description: "Count the mul instructions of the coprocessor program, then the value of its register h",
+    input: "input/2017/day23",
+    parts: &[one, two],
+    params: &[],
+    generate,
+    checks: &[CHECK],
+    parse: puzzle::parse::<Day23>,
+    solve: puzzle::solve::<Day23>,
+};
+
+/// Runs the program in non-debug mode, to cross-check the count of composite numbers
+/// on programs counting among small numbers
+pub const CHECK: Check = Check {
+    part: 2,
+    reference: two_brute_force,
+    generate: generate_small,
+    params: &[],
+    shrink,
+};
+
+/// Represents the right-hand value of an instruction.
+/// It either contains a direct value, as an Integer,
+/// or refers to a register's content.
+#[derive(Clone, Copy, Debug)]
+pub enum Value {
+    Integer(isize),
+    Register(char),
+}
+
+/// Parses a value description into a Value enum,
+/// depending on its nature.
+pub fn parse_val(l: &mut Scanner) -> Result<Value, SolveError> {
+    if l.at_number() {
+        l.number().map(Integer)
+    } else {
+        l.letter().map(Register)
+    }
+}
+
+/// Represents a Duet assembly instruction
+#[derive(Clone, Copy, Debug)]
+pub enum Op {
+    Set(char, Value),
+    Sub(char, Value),
+    Mul(char, Value),
+    Jnz(Value, Value),
+}
+
+/// Parses an instruction description into an Op enum.
+pub fn parse_op(l: &mut Scanner) -> Result<Op, SolveError> {
+    l.skip_whitespace();
+    let column = l.column();
+    let op = match l.name()? {
+        "set" => Set(l.letter()?, parse_val(l)?),
+        "sub" => Sub(l.letter()?, parse_val(l)?),
+        "mul" => Mul(l.letter()?, parse_val(l)?),
+        "jnz" => Jnz(parse_val(l)?, parse_val(l)?),
+        op => return Err(l.error_at(column, format!("Unknown instruction {}", op))),
+    };
+    Ok(op)
+}
+
+/// Parses the complete program in Duet assembly
+/// into a vector of instructions
+pub fn parse(s: &str) -> Result<Vec<Op>, SolveError> {
+    scan::lines(s, parse_op)
+}
+
+/// Represents a running program
+struct Program {
+    regs: HashMap<char, isize>,
+    instructions: Vec<Op>,
+    pos: usize,
+}
+
+/// Represents the external behavior of the program:
+/// whether it just sent a value,
+/// is waiting to receive one because its buffer is empty,
+/// or has left its code space and has terminated.
+pub enum ProgramIO {
+    Sent(isize),
+    Receive,
+    Terminate,
+}
+
+impl Program {
+    /// Create a new program with the corresponding id and code
+    fn new(id: isize, instructions: &[Op]) -> Self {
+        let mut regs = HashMap::new();
+        regs.insert('p', id);
+        Program {
+            regs,
+            instructions: instructions.to_vec(),
+            pos: 0,
+        }
+    }
+
+    /// Retrieve the value of a right-hand member
+    /// in the context of the program, i.e. the value of the Integer
+    /// or the content of the Register
+    fn get(&self, v: &Value) -> isize {
+        match *v {
+            Integer(i) => i,
+            Register(c) => *self.regs.get(&c).unwrap_or(&0),
+        }
+    }
+
+    /// Executes the current instruction if possible,
+    /// and returns the emitted IO state if applicable
+    fn exec(&mut self) -> Option<Op> {
+        let op = match self.instructions.get(self.pos) {
+            Some(op) => *op,
+            None => return None,
+        };
+        let mut next_address = self.pos + 1;
+        match op {
+            Set(c, ref v) => {
+                let val = self.get(v);
+                self.regs.insert(c, val);
+            }
+            Sub(c, ref v) => {
+                *self.regs.entry(c).or_insert(0) -= self.get(v);
+            }
+            Mul(c, ref v) => {
+                *self.regs.entry(c).or_insert(0) *= self.get(v);
+            }
+            Jnz(ref v, ref j) => {
+                if self.get(v) != 0 {
+                    next_address = (self.pos as isize + self.get(j)) as usize;
+                }
+            }
+        };
+        self.pos = next_address;
+        Some(op)
+    }
+}
+
+impl Iterator for Program {
+    type Item = Op;
+    /// Executes the instructions until the program
+    /// either terminates, sends a value,
+    /// or waits for its buffer to be filled.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.exec()
+    }
+}
+
+/// Solver of the coprocessor, working on the instructions of the program
+pub struct Day23;
+
+impl Day for Day23 {
+    type Input = Vec<Op>;
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        parse(s)
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<Answer, SolveError> {
+        let program = Program::new(0, instructions);
+        Ok(program
+            .filter(|x| matches!(*x, Mul(_, _)))
+            .count()
+            .into())
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<Answer, SolveError> {
+        let shape = || SolveError::InvalidInput(String::from("The program does not count composite numbers"));
+
+        // Initialises the registers in non-debug mode, up to the outer loop
+        let mut program = Program::new(0, instructions);
+        program.regs.insert('a', 1);
+        let outer_loop = |op: &Op| matches!(*op, Set('f', Integer(1)));
+        for _ in 0..instructions.len() {
+            match program.instructions.get(program.pos) {
+                Some(op) if !outer_loop(op) => program.exec(),
+                _ => break,
+            };
+        }
+        if !program.instructions.get(program.pos).is_some_and(outer_loop) {
+            return Err(shape());
+        }
+
+        // The outer loop goes from b to c, incrementing b by a step
+        let step = instructions
+            .iter()
+            .rev()
+            .find_map(|op| match *op {
+                Sub('b', Integer(s)) if s < 0 => Some(-s),
+                _ => None,
+            })
+            .ok_or_else(shape)?;
+        let mut b = program.get(&Register('b'));
+        let c = program.get(&Register('c'));
+        let mut h = 0;
+        while b <= c {
+            if (2..b).any(|x| b % x == 0) {
+                h += 1;
+            }
+            b += step;
+        }
+
+        Ok(h.into())
+    }
+}
+
+/// Launch a single program of id 0,
+/// and inspect the number of mul instructions
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day23::part1(&puzzle::read::<Day23>(s)?)
+}
+
+/// Launch a single program of id 0,
+/// and inspect the value of register h.
+pub fn two_brute_force(s: &str) -> Result<Answer, SolveError> {
+    let instructions = parse(s)?;
+    let mut program = Program::new(0, &instructions);
+    program.regs.insert('a', 1);
+    for i in 0.. {
+        if program.next().is_none() {
+            break;
+        }
+        if i % 100_000 == 0 {
+            cancel::check()?;
+            progress::report(i, None);
+        }
+    }
+
+    Ok(Answer::from(*program.regs.get(&'h').unwrap_or(&0)))
+}
+
+/// Simulates the non-debug program.
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day23::part2(&puzzle::read::<Day23>(s)?)
+}
+
+/// Program of the shape of the puzzle inputs, counting the composite numbers
+/// from `b * factor + offset` to `range` further, with a step of 17
+fn program(b: i64, factor: i64, offset: i64, range: i64) -> String {
+    format!(
+        "\
+set b {}
+set c b
+jnz a 2
+jnz 1 5
+mul b {}
+sub b {}
+set c b
+sub c {}
+set f 1
+set d 2
+set e 2
+set g d
+mul g e
+sub g b
+jnz g 2
+set f 0
+sub e -1
+set g e
+sub g b
+jnz g -8
+sub d -1
+set g d
+sub g b
+jnz g -13
+jnz f 2
+sub h -1
+set g b
+sub g c
+jnz g 2
+jnz 1 3
+sub b -17
+jnz 1 -23",
+        b, factor, -offset, -range
+    )
+}
+
+/// Constants of a program generated by `program`
+fn constants(s: &str) -> Option<(i64, i64, i64, i64)> {
+    let value = |i: usize| -> Option<i64> { s.lines().nth(i)?.split_whitespace().nth(2)?.parse().ok() };
+    Some((value(0)?, value(4)?, -value(5)?, -value(7)?))
+}
+
+/// Programs counting the composite numbers in a smaller range,
+/// from smaller numbers
+fn shrink(s: &str) -> Vec<String> {
+    let (b, factor, offset, range) = match constants(s) {
+        Some(constants) => constants,
+        None => return vec![],
+    };
+    let mut candidates = Vec::new();
+    if range > 0 {
+        candidates.push(program(b, factor, offset, range / 34 * 17));
+    }
+    if b / 2 >= 3 {
+        candidates.push(program(b / 2, factor, offset, range));
+    }
+    if factor > 1 {
+        candidates.push(program(b, 1, offset, range));
+    }
+    if offset > 0 {
+        candidates.push(program(b, factor, offset / 2, range));
+    }
+    candidates
+}
+
+/// Generates a program of the shape of the puzzle inputs,
+/// counting the composite numbers in a range depending on a random seed
+pub fn generate(rng: &mut Rng, _scale: usize) -> String {
+    program(rng.range(57, 99), 100, 100_000, 17_000)
+}
+
+/// Generates a program counting the composite numbers among a few small ones,
+/// so that it runs quickly in non-debug mode
+fn generate_small(rng: &mut Rng, _scale: usize) -> String {
+    program(rng.range(3, 20), rng.range(1, 4), rng.range(0, 50), 17 * rng.range(0, 5))
+}
@@ -0,0 +1,136 @@
+use std::collections::HashSet;
+use std::cmp::Ordering;
+use generate::Rng;
+use puzzle::{self, Day, Puzzle};
+use scan::{self, Scanner};
+use {Answer, SolveError};
+
+/// Day 24: Electromagnetic Moat
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 24,
+    title: "Electromagnetic Moat",
+    description: "Strength of the strongest bridge of components, then of the longest one",
+    input: "input/2017/day24",
+    parts: &[one, two],
+    params: &[],
+    generate,
+    checks: &[],
+    parse: puzzle::parse::<Day24>,
+    solve: puzzle::solve::<Day24>,
+};
+
+/// The components still available to build a bridge
+#[derive(Clone)]
+pub struct Tubes {
+    tubes: HashSet<(usize, usize)>,
+}
+
+impl Tubes {
+    fn parse_tube(l: &mut Scanner) -> Result<(usize, usize), SolveError> {
+        let a = l.number()?;
+        l.expect("/")?;
+        Ok((a, l.number()?))
+    }
+
+    fn parse_tubes(s: &str) -> Result<Self, SolveError> {
+        let tubes = scan::lines(s, Self::parse_tube)?.into_iter().collect();
+        Ok(Tubes { tubes })
+    }
+
+    fn compatible(&self, port: usize) -> Vec<(usize, usize)> {
+        self.tubes
+            .iter()
+            .filter(|&&(a, b)| port == a || port == b)
+            .cloned()
+            .collect()
+    }
+
+    fn extend_strong(&mut self, port: usize) -> Vec<(usize, usize)> {
+        self.compatible(port)
+            .into_iter()
+            .map(|tube| {
+                let other = other_port(&tube, port);
+                self.tubes.remove(&tube);
+                let mut bridge = vec![tube];
+                bridge.append(&mut self.extend_strong(other));
+                self.tubes.insert(tube);
+                bridge
+            })
+            .max_by_key(|bridge| strength(bridge))
+            .unwrap_or_default()
+    }
+
+    fn extend_long(&mut self, port: usize) -> Vec<(usize, usize)> {
+        self.compatible(port)
+            .into_iter()
+            .map(|tube| {
+                let other = other_port(&tube, port);
+                self.tubes.remove(&tube);
+                let mut bridge = vec![tube];
+                bridge.append(&mut self.extend_long(other));
+                self.tubes.insert(tube);
+                bridge
+            })
+            .max_by(|a, b| cmp_bridge(a, b))
+            .unwrap_or_default()
+    }
+}
+
+fn other_port(tube: &(usize, usize), port: usize) -> usize {
+    let &(a, b) = tube;
+    if port == a {
+        b
+    } else {
+        a
+    }
+}
+
+fn strength(bridge: &[(usize, usize)]) -> usize {
+    bridge.iter().map(|&(a, b)| a + b).sum()
+}
+
+/// Solver of the bridges, working on the available components
+pub struct Day24;
+
+impl Day for Day24 {
+    type Input = Tubes;
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        Tubes::parse_tubes(s)
+    }
+
+    fn part1(tubes: &Self::Input) -> Result<Answer, SolveError> {
+        let bridge = tubes.clone().extend_strong(0);
+        Ok(strength(&bridge).into())
+    }
+
+    fn part2(tubes: &Self::Input) -> Result<Answer, SolveError> {
+        let bridge = tubes.clone().extend_long(0);
+        Ok(strength(&bridge).into())
+    }
+}
+
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day24::part1(&puzzle::read::<Day24>(s)?)
+}
+
+fn cmp_bridge(a: &[(usize, usize)], b: &[(usize, usize)]) -> Ordering {
+    a.len().cmp(&b.len()).then(strength(a).cmp(&strength(b)))
+}
+
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day24::part2(&puzzle::read::<Day24>(s)?)
+}
+
+/// Generates random components, a few of them with a port of 0 pins
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut components: Vec<String> = (0..57 * scale)
+        .map(|i| {
+            let a = if i < 3 { 0 } else { rng.range(1, 50) };
+            format!("{}/{}", a, rng.range(1, 50))
+        })
+        .collect();
+    rng.shuffle(&mut components);
+    components.join("\n")
+}
@@ -0,0 +1,340 @@
+use std::collections::{HashMap, HashSet};
+use self::Dir::{Left, Right};
+use cancel;
+use generate::Rng;
+use progress;
+use puzzle::{self, Day, Puzzle};
+use scan::{self, Scanner};
+use {Answer, SolveError};
+
+/// Day 25: The Halting Problem
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 25,
+    title: "The Halting Problem",
+    description: "Diagnostic checksum of the Turing machine after its blueprint runs",
+    input: "input/2017/day25",
+    parts: &[one],
+    params: &[],
+    generate,
+    checks: &[],
+    parse: puzzle::parse::<Day25>,
+    solve: puzzle::solve::<Day25>,
+};
+
+enum Dir {
+    Left,
+    Right,
+}
+
+struct Rule {
+    value: bool,
+    direction: Dir,
+    next: char,
+}
+
+impl Rule {
+    fn new(value: bool, direction: Dir, next: char) -> Rule {
+        Rule {
+            value,
+            direction,
+            next,
+        }
+    }
+}
+
+struct State {
+    zero: Rule,
+    one: Rule,
+}
+
+impl State {
+    fn from_rules(zero: Rule, one: Rule) -> State {
+        State { zero, one }
+    }
+}
+
+/// Blueprint of the Turing machine
+pub struct Blueprint {
+    init: char,
+    states: HashMap<char, State>,
+    duration: usize,
+}
+
+/// Line of the blueprint, once parsed
+enum Line {
+    Blank,
+    Begin(char),
+    Steps(usize),
+    State(char),
+    Value(usize),
+    Write(bool),
+    Move(Dir),
+    Continue(char),
+}
+
+/// Reads a value of the tape, 0 or 1
+fn bit(l: &mut Scanner) -> Result<usize, SolveError> {
+    l.skip_whitespace();
+    let column = l.column();
+    match l.number()? {
+        v @ 0..=1 => Ok(v),
+        _ => Err(l.error_at(column, "The value should be 0 or 1")),
+    }
+}
+
+/// Reads a sentence of the blueprint
+fn parse_line(l: &mut Scanner) -> Result<Line, SolveError> {
+    let line = if l.at_end() {
+        return Ok(Line::Blank);
+    } else if l.eat("Begin in state") {
+        Line::Begin(l.letter()?)
+    } else if l.eat("Perform a diagnostic checksum after") {
+        let steps = l.number()?;
+        l.expect("steps")?;
+        Line::Steps(steps)
+    } else if l.eat("In state") {
+        let name = l.letter()?;
+        l.expect(":")?;
+        return Ok(Line::State(name));
+    } else if l.eat("If the current value is") {
+        let value = bit(l)?;
+        l.expect(":")?;
+        return Ok(Line::Value(value));
+    } else if l.eat("Write the value") {
+        Line::Write(bit(l)? == 1)
+    } else if l.eat("Move one slot to the") {
+        l.skip_whitespace();
+        let column = l.column();
+        match l.name()? {
+            "left" => Line::Move(Left),
+            "right" => Line::Move(Right),
+            _ => return Err(l.error_at(column, "The direction should be left or right")),
+        }
+    } else if l.eat("Continue with state") {
+        Line::Continue(l.letter()?)
+    } else {
+        return Err(l.error("Unexpected line in the blueprint"));
+    };
+    l.expect(".")?;
+    Ok(line)
+}
+
+/// Rule being parsed, as the value to write, the direction and the next state
+type PartialRule = (Option<bool>, Option<Dir>, Option<char>);
+
+/// State being parsed, with its rules for each current value
+type PartialState = (char, [PartialRule; 2]);
+
+/// Rule of the state being parsed for the current value
+fn rule_mut(current: &mut Option<PartialState>, value: usize) -> Result<&mut PartialRule, &'static str> {
+    match *current {
+        Some((_, ref mut rules)) => Ok(&mut rules[value]),
+        None => Err("Rule outside of a state"),
+    }
+}
+
+/// Builds a state once all its rules have been parsed
+fn complete((name, [zero, one]): PartialState) -> Result<(char, State), SolveError> {
+    let rule = |partial: PartialRule| match partial {
+        (Some(value), Some(direction), Some(next)) => Ok(Rule::new(value, direction, next)),
+        _ => Err(SolveError::InvalidInput(format!(
+            "The rules of state {} are incomplete",
+            name
+        ))),
+    };
+    Ok((name, State::from_rules(rule(zero)?, rule(one)?)))
+}
+
+/// Parses the blueprint, as written in the puzzle input
+fn parse_blueprint(s: &str) -> Result<Blueprint, SolveError> {
+    let mut init = None;
+    let mut duration = None;
+    let mut states = HashMap::new();
+    let mut current: Option<PartialState> = None;
+    let mut value = 0;
+
+    scan::lines(s, |l| {
+        // The rules are list items
+        l.eat("-");
+        l.skip_whitespace();
+        let column = l.column();
+        match parse_line(l)? {
+            Line::Blank => {}
+            Line::Begin(name) => init = Some(name),
+            Line::Steps(steps) => duration = Some(steps),
+            Line::State(name) => {
+                if let Some(partial) = current.take() {
+                    let (name, state) = complete(partial)?;
+                    states.insert(name, state);
+                }
+                current = Some((name, Default::default()));
+            }
+            Line::Value(v) => value = v,
+            Line::Write(v) => {
+                rule_mut(&mut current, value).map_err(|e| l.error_at(column, e))?.0 = Some(v)
+            }
+            Line::Move(d) => {
+                rule_mut(&mut current, value).map_err(|e| l.error_at(column, e))?.1 = Some(d)
+            }
+            Line::Continue(next) => {
+                rule_mut(&mut current, value).map_err(|e| l.error_at(column, e))?.2 = Some(next)
+            }
+        }
+        Ok(())
+    })?;
+    if let Some(partial) = current.take() {
+        let (name, state) = complete(partial)?;
+        states.insert(name, state);
+    }
+
+    let missing = |what: &str| SolveError::InvalidInput(format!("The blueprint has no {}", what));
+    let init = init.ok_or_else(|| missing("initial state"))?;
+    let duration = duration.ok_or_else(|| missing("number of steps"))?;
+    let known = |name: char| states.contains_key(&name);
+    if !known(init) || !states.values().all(|s| known(s.zero.next) && known(s.one.next)) {
+        return Err(missing("rules for one of its states"));
+    }
+    Ok(Blueprint {
+        init,
+        states,
+        duration,
+    })
+}
+
+struct Turing<'a> {
+    step: usize,
+    state: char,
+    tape: HashSet<isize>,
+    cursor: isize,
+    blueprint: &'a Blueprint,
+}
+
+impl<'a> Turing<'a> {
+    fn new(blueprint: &'a Blueprint) -> Self {
+        Turing {
+            step: 0,
+            state: blueprint.init,
+            tape: HashSet::new(),
+            cursor: 0,
+            blueprint,
+        }
+    }
+
+    fn next(&mut self) -> (usize, char, isize, bool) {
+        self.step += 1;
+
+        let state = self.blueprint
+            .states
+            .get(&self.state)
+            .unwrap_or_else(|| panic!("No rule for state {}", self.state));
+
+        let rule = if self.tape.contains(&self.cursor) {
+            &state.one
+        } else {
+            &state.zero
+        };
+
+        if rule.value {
+            self.tape.insert(self.cursor);
+        } else {
+            self.tape.remove(&self.cursor);
+        }
+
+        match rule.direction {
+            Right => self.cursor += 1,
+            Left => self.cursor -= 1,
+        }
+
+        self.state = rule.next;
+
+        (
+            self.step,
+            self.state,
+            self.cursor,
+            self.tape.contains(&self.cursor),
+        )
+    }
+}
+
+/// Solver of the Turing machine, working on its blueprint
+pub struct Day25;
+
+impl Day for Day25 {
+    type Input = Blueprint;
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        parse_blueprint(s)
+    }
+
+    fn part1(blueprint: &Self::Input) -> Result<Answer, SolveError> {
+        let mut turing = Turing::new(blueprint);
+
+        for i in 0..turing.blueprint.duration {
+            turing.next();
+            if i % 1_000_000 == 0 {
+                cancel::check()?;
+                progress::report(i as u64, Some(turing.blueprint.duration as u64));
+            }
+        }
+
+        Ok(turing.tape.len().into())
+    }
+}
+
+/// Runs the Turing machine for the number of steps of its blueprint,
+/// and counts the ones written on the tape
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day25::one;
+/// let blueprint = "\
+/// Begin in state A.
+/// Perform a diagnostic checksum after 6 steps.
+///
+/// In state A:
+///   If the current value is 0:
+///     - Write the value 1.
+///     - Move one slot to the right.
+///     - Continue with state B.
+///   If the current value is 1:
+///     - Write the value 0.
+///     - Move one slot to the left.
+///     - Continue with state B.
+///
+/// In state B:
+///   If the current value is 0:
+///     - Write the value 1.
+///     - Move one slot to the left.
+///     - Continue with state A.
+///   If the current value is 1:
+///     - Write the value 1.
+///     - Move one slot to the right.
+///     - Continue with state A.";
+/// assert_eq!("3", one(blueprint).unwrap());
+/// ```
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day25::part1(&puzzle::read::<Day25>(s)?)
+}
+
+/// Generates a random blueprint with 6 states
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let states: Vec<char> = (b'A'..b'G').map(char::from).collect();
+    let mut blueprint = format!(
+        "Begin in state A.\nPerform a diagnostic checksum after {} steps.\n",
+        rng.range(10_000_000, 13_000_000) * scale as i64
+    );
+    for state in &states {
+        blueprint += &format!("\nIn state {}:\n", state);
+        for value in 0..2 {
+            blueprint += &format!(
+                "  If the current value is {}:\n    - Write the value {}.\n    - Move one slot to the {}.\n    - Continue with state {}.\n",
+                value,
+                rng.below(2),
+                rng.choose(&["left", "right"]),
+                rng.choose(&states)
+            );
+        }
+    }
+    blueprint
+}
@@ -0,0 +1,132 @@
+use generate::Rng;
+use grid::{Point, Sparse};
+use puzzle::{self, Day, Puzzle};
+use scan;
+use {Answer, SolveError};
+
+/// Day 3: Spiral Memory
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 3,
+    title: "Spiral Memory",
+    description: "Distance from a square of the spiral memory to its centre, then the first cumulated value above the input",
+    input: "input/2017/day3",
+    parts: &[one, two],
+    params: &[],
+    generate,
+    checks: &[],
+    parse: puzzle::parse::<Day3>,
+    solve: puzzle::solve::<Day3>,
+};
+
+/// Takes in the index of the cell,
+/// and returns the coordinates relative to the cell #1 in the spiral.
+fn spiral_coord_from_index(index: u64) -> Point {
+    // Let us determine the side length of the smallest square
+    // centered around the cell #1 containing the index:
+    let square_length = (0..)
+        .map(|x| 2 * x + 1)
+        .find(|&x| index <= x * x)
+        .unwrap_or(1) - 1;
+
+    if square_length < 2 {
+        // Center of the spiral
+        return Point::new(0, 0);
+    }
+
+    let half_length = (square_length / 2) as i64;
+    if (square_length + 1) * (square_length + 1) == index {
+        // Bottom-right corner
+        return Point::new(half_length, -half_length);
+    }
+
+    // We find on which side of the square the cell is,
+    // running counter-clockwise from the bottom-right corner.
+    let start_index = (square_length - 1) * (square_length - 1);
+    let which_side = (index - start_index) / square_length;
+    let rem = ((index - start_index) % square_length) as i64;
+    let (x, y) = match which_side {
+        0 => (half_length, rem - half_length),
+        1 => (half_length - rem, half_length),
+        2 => (-half_length, half_length - rem),
+        3 => (rem - half_length, -half_length),
+        _ => unreachable!(),
+    };
+    Point::new(x, y)
+}
+
+/// Solver of the spiral memory, working on the index of the cell
+pub struct Day3;
+
+impl Day for Day3 {
+    type Input = u64;
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        scan::line(s, |l| l.number())
+    }
+
+    fn part1(&index: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(spiral_coord_from_index(index).manhattan().into())
+    }
+
+    fn part2(&objective: &Self::Input) -> Result<Answer, SolveError> {
+        let mut cells = Sparse::new();
+
+        let mut index = 1;
+        let mut val = 1;
+        cells.insert(Point::new(0, 0), val);
+
+        while val < objective {
+            index += 1;
+            let cell = spiral_coord_from_index(index);
+            // We compute the sum of the values of all its neighbors
+            val = sum_neighbors(cell, &cells);
+            cells.insert(cell, val);
+        }
+
+        Ok(val.into())
+    }
+}
+
+/// Finds the distance from the center of the spiral
+/// to the provided index.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day3::one;
+/// assert_eq!("0", one("1").unwrap());
+/// assert_eq!("3", one("12").unwrap());
+/// assert_eq!("2", one("23").unwrap());
+/// assert_eq!("31", one("1024").unwrap());
+/// ```
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day3::part1(&puzzle::read::<Day3>(s)?)
+}
+
+fn sum_neighbors(target: Point, cells: &Sparse<u64>) -> u64 {
+    target
+        .neighbors8()
+        .iter()
+        .filter_map(|&c| cells.get(c))
+        .sum()
+}
+
+/// Finds the first element of the "cumulative" spiral
+/// which is larger than the provided value
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day3::two;
+/// assert_eq!("1", two("1").unwrap());
+/// assert_eq!("2", two("2").unwrap());
+/// assert_eq!("4", two("3").unwrap());
+/// assert_eq!("10", two("6").unwrap());
+/// ```
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day3::part2(&puzzle::read::<Day3>(s)?)
+}
+
+/// Generates a random square of the spiral memory
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    (rng.range(1_000, 500_000) * scale as i64).to_string()
+}
@@ -0,0 +1,144 @@
+use generate::Rng;
+use puzzle::{self, Day, Puzzle};
+use scan::{self, Scanner};
+use {Answer, SolveError};
+
+/// Day 4: High-Entropy Passphrases
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 4,
+    title: "High-Entropy Passphrases",
+    description: "Count the passphrases without repeated words, then without anagrams",
+    input: "input/2017/day4",
+    parts: &[one, two],
+    params: &[],
+    generate,
+    checks: &[],
+    parse: puzzle::parse::<Day4>,
+    solve: puzzle::solve::<Day4>,
+};
+
+/// Parses a passphrase, made of words separated by spaces
+fn passphrase(l: &mut Scanner) -> Result<String, SolveError> {
+    let words = l.repeat(|l| l.token("a word", char::is_alphanumeric))?;
+    Ok(words.join(" "))
+}
+
+/// Solver of the passphrases, working on the lines of the input
+pub struct Day4;
+
+impl Day for Day4 {
+    type Input = Vec<String>;
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        scan::lines(s, passphrase)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(lines.iter().filter(|s| valid(s)).count().into())
+    }
+
+    fn part2(lines: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(lines.iter()
+            .filter(|s| valid_anagram(s))
+            .count()
+            .into())
+    }
+}
+
+/// Checks the validity of a passphrase
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day4::valid;
+/// assert!(valid("aa bb cc dd ee"));
+/// assert!(!valid("aa bb cc dd aa"));
+/// assert!(valid("aa bb cc dd aaa"));
+/// ```
+pub fn valid(s: &str) -> bool {
+    let mut words: Vec<&str> = s.split_whitespace().collect();
+    let nb_words = words.len();
+    if nb_words == 0 {
+        return false;
+    }
+    words.sort();
+    words.dedup();
+    let nb_unique = words.len();
+    nb_unique == nb_words
+}
+
+/// Counts the number of valid passphrases in the input
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day4::part1(&puzzle::read::<Day4>(s)?)
+}
+
+/// Sorts the characters of a word
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day4::sort_word;
+/// assert_eq!("ceelmow", sort_word("welcome"));
+/// ```
+pub fn sort_word(w: &str) -> String {
+    let mut chars: Vec<char> = w.chars().collect();
+    chars.sort();
+    chars.into_iter().collect()
+}
+
+/// Checks the validity of a passphrase forbidding anagrams
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day4::valid_anagram;
+/// assert!(valid_anagram("abcde fghij"));
+/// assert!(!valid_anagram("abcde xyz ecdab"));
+/// assert!(valid_anagram("a ab abc abd abf abj"));
+/// assert!(valid_anagram("iiii oiii ooii oooi oooo"));
+/// assert!(!valid_anagram("oiii ioii iioi iiio"));
+/// ```
+pub fn valid_anagram(s: &str) -> bool {
+    let mut words: Vec<String> = s.split_whitespace().map(sort_word).collect();
+    let nb_words = words.len();
+    if nb_words == 0 {
+        return false;
+    }
+
+    words.sort();
+    words.dedup();
+    let nb_unique = words.len();
+    nb_unique == nb_words
+}
+
+/// Counts the number of valid passphrases in the input,
+/// banning anagrams
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day4::part2(&puzzle::read::<Day4>(s)?)
+}
+
+/// Generates random passphrases,
+/// some of them repeating a word or holding anagrams
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let passphrases: Vec<String> = (0..512 * scale)
+        .map(|_| {
+            let n = rng.range(3, 11) as usize;
+            let mut words: Vec<String> = (0..n)
+                .map(|_| {
+                    let len = rng.range(2, 7) as usize;
+                    rng.word(len)
+                })
+                .collect();
+            if rng.one_in(4) {
+                let copy = words[rng.below(n)].clone();
+                words.push(copy);
+            }
+            if rng.one_in(4) {
+                let mut anagram: Vec<char> = words[rng.below(n)].chars().collect();
+                rng.shuffle(&mut anagram);
+                words.push(anagram.into_iter().collect());
+            }
+            rng.shuffle(&mut words);
+            words.join(" ")
+        })
+        .collect();
+    passphrases.join("\n")
+}
@@ -0,0 +1,104 @@
+use cancel;
+use generate::Rng;
+use puzzle::{self, Day, Puzzle};
+use scan;
+use {Answer, SolveError};
+
+/// Day 5: A Maze of Twisty Trampolines, All Alike
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 5,
+    title: "A Maze of Twisty Trampolines, All Alike",
+    description: "Count the jumps needed to leave a list of self-modifying offsets",
+    input: "input/2017/day5",
+    parts: &[one, two],
+    params: &[],
+    generate,
+    checks: &[],
+    parse: puzzle::parse::<Day5>,
+    solve: puzzle::solve::<Day5>,
+};
+
+/// Solver of the jumps, working on the list of offsets
+pub struct Day5;
+
+impl Day for Day5 {
+    type Input = Vec<isize>;
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        scan::numbers(s)
+    }
+
+    fn part1(offsets: &Self::Input) -> Result<Answer, SolveError> {
+        let mut offsets = offsets.clone();
+        let size = offsets.len();
+        let mut index = 0;
+        let mut steps = 0;
+        while index < size {
+            steps += 1;
+            if steps % 1_000_000 == 0 {
+                cancel::check()?;
+            }
+            offsets[index] += 1;
+            let new_index = (index as isize) + offsets[index] - 1;
+            if new_index < 0 {
+                break;
+            }
+            index = new_index as usize;
+        }
+        Ok(steps.into())
+    }
+
+    fn part2(offsets: &Self::Input) -> Result<Answer, SolveError> {
+        let mut offsets = offsets.clone();
+        let size = offsets.len();
+        let mut index = 0;
+        let mut steps = 0;
+        while index < size {
+            steps += 1;
+            if steps % 1_000_000 == 0 {
+                cancel::check()?;
+            }
+
+            let old_offset = offsets[index];
+            offsets[index] += if old_offset >= 3 { -1 } else { 1 };
+
+            let new_index = (index as isize) + old_offset;
+            if new_index < 0 {
+                break;
+            }
+            index = new_index as usize;
+        }
+        Ok(steps.into())
+    }
+}
+
+/// Calculates the number of steps to leave the list
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day5::one;
+/// assert_eq!("5", one("0 3 0 1 -3").unwrap());
+/// ```
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day5::part1(&puzzle::read::<Day5>(s)?)
+}
+
+/// Calculates the number of steps to leave the list
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day5::two;
+/// assert_eq!("10", two("0 3 0 1 -3").unwrap());
+/// ```
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day5::part2(&puzzle::read::<Day5>(s)?)
+}
+
+/// Generates random jump offsets, mostly backwards
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let offsets: Vec<String> = (0..1000 * scale as i64)
+        .map(|i| rng.range(-i, 2).to_string())
+        .collect();
+    offsets.join("\n")
+}
@@ -0,0 +1,141 @@
+use cycle::{self, Cycle};
+use generate::Rng;
+use puzzle::{self, Day, Puzzle};
+use scan;
+use {Answer, SolveError};
+
+/// Day 6: Memory Reallocation
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 6,
+    title: "Memory Reallocation",
+    description: "Count the redistributions before a memory state repeats, then the length of the loop",
+    input: "input/2017/day6",
+    parts: &[one, two],
+    params: &[],
+    generate,
+    checks: &[],
+    parse: puzzle::parse::<Day6>,
+    solve: puzzle::solve::<Day6>,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct MemoryBank {
+    pub bank: Vec<usize>,
+}
+
+impl MemoryBank {
+    /// Parses the number of blocks of each bank, which must have one bank at least
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::y2017::day6::MemoryBank;
+    /// assert_eq!(4, MemoryBank::parse("0 2 7 0").unwrap().bank.len());
+    /// assert!(MemoryBank::parse("").is_err());
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, SolveError> {
+        let bank = scan::numbers(s)?;
+        if bank.is_empty() {
+            return Err(SolveError::InvalidInput(String::from(
+                "There should be at least one memory bank",
+            )));
+        }
+        Ok(MemoryBank { bank })
+    }
+
+    /// Redistributes the blocks of the bank with the most blocks,
+    /// leaving a memory without banks unchanged
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::y2017::day6::MemoryBank;
+    /// let b = MemoryBank::parse("0 2 7 0").unwrap();
+    /// let b = b.redistribute();
+    /// assert_eq!(b.bank, [2, 4, 1, 2]);
+    /// let b = b.redistribute();
+    /// assert_eq!(b.bank, [3, 1, 2, 3]);
+    /// let b = b.redistribute();
+    /// assert_eq!(b.bank, [0, 2, 3, 4]);
+    /// let b = b.redistribute();
+    /// assert_eq!(b.bank, [1, 3, 4, 1]);
+    /// let b = b.redistribute();
+    /// assert_eq!(b.bank, [2, 4, 1, 2]);
+    /// ```
+    pub fn redistribute(&self) -> Self {
+        let n = self.bank.len();
+        if n == 0 {
+            return self.clone();
+        }
+        let (i_max, &max) = self.bank
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|&(_, val)| val)
+            .unwrap_or((0, &0));
+        let q = max / n;
+        let r = max % n;
+        let mut new_bank = self.clone();
+        new_bank.bank[i_max] = 0;
+        for i in 0..n {
+            new_bank.bank[i] += q;
+            if (n + i - (i_max + 1)) % n < r {
+                new_bank.bank[i] += 1;
+            }
+        }
+        new_bank
+    }
+}
+
+/// Solver of the reallocation, working on the initial memory bank
+pub struct Day6;
+
+impl Day for Day6 {
+    type Input = MemoryBank;
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        MemoryBank::parse(s)
+    }
+
+    fn part1(bank: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(reallocation_loop(bank).first_repeat().into())
+    }
+
+    fn part2(bank: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(reallocation_loop(bank).length.into())
+    }
+}
+
+/// Loop of the memory states, reached after the tail of the redistributions
+fn reallocation_loop(bank: &MemoryBank) -> Cycle {
+    cycle::brent(bank.clone(), MemoryBank::redistribute)
+}
+
+/// Finds the number of iterations before looping
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day6::one;
+/// assert_eq!("5", one("0 2 7 0").unwrap());
+/// ```
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day6::part1(&puzzle::read::<Day6>(s)?)
+}
+
+/// Finds the number of cycles in the infinite loop
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day6::two;
+/// assert_eq!("4", two("0 2 7 0").unwrap());
+/// ```
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day6::part2(&puzzle::read::<Day6>(s)?)
+}
+
+/// Generates random memory banks
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let banks: Vec<String> = (0..16)
+        .map(|_| rng.range(0, 15 * scale as i64).to_string())
+        .collect();
+    banks.join("\t")
+}
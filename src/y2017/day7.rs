@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use generate::Rng;
+use graph::{Graph, Names};
+use puzzle::{self, Day, Puzzle};
+use scan::{self, Scanner};
+use {Answer, SolveError};
+
+/// Day 7: Recursive Circus
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 7,
+    title: "Recursive Circus",
+    description: "Find the bottom program of the tower, then the weight fixing its unbalanced program",
+    input: "input/2017/day7",
+    parts: &[one, two],
+    params: &[],
+    generate,
+    checks: &[],
+    parse: puzzle::parse::<Day7>,
+    solve: puzzle::solve::<Day7>,
+};
+
+/// The tower of programs, each program being a node
+/// with an edge to every program it holds
+pub struct Tower {
+    names: Names,
+    weights: Vec<usize>,
+    programs: Graph,
+    root: usize,
+    /// Weight of each program with the programs it holds
+    cumulated_weights: Vec<usize>,
+}
+
+/// Parses a program description: its name, its weight
+/// and the names of the programs it holds
+fn parse_program<'a>(l: &mut Scanner<'a>) -> Result<(&'a str, usize, Vec<&'a str>), SolveError> {
+    let name = l.name()?;
+    l.expect("(")?;
+    let weight = l.number()?;
+    l.expect(")")?;
+    let children = if l.eat("->") {
+        l.separated(",", |l| l.name())?
+    } else {
+        vec![]
+    };
+    Ok((name, weight, children))
+}
+
+/// Builds the graph of the programs,
+/// then finds its root and the cumulated weights.
+fn parse_tower(s: &str) -> Result<Tower, SolveError> {
+    let mut names = Names::new();
+    let mut weights = HashMap::new();
+    let mut programs = Graph::new(0);
+    for (name, weight, children) in scan::lines(s, parse_program)? {
+        let id = names.id(name);
+        weights.insert(id, weight);
+        for child in children {
+            programs.add_edge(id, names.id(child));
+        }
+    }
+    let weights = (0..names.len())
+        .map(|id| {
+            weights.get(&id).cloned().ok_or_else(|| {
+                SolveError::InvalidInput(format!("Program {} is not described", names.name(id)))
+            })
+        })
+        .collect::<Result<Vec<usize>, SolveError>>()?;
+
+    let roots = programs.roots();
+    if roots.len() != 1 {
+        Err(SolveError::InvalidInput(String::from("The tree has no single root")))?
+    }
+    let root = roots[0];
+
+    // Every program comes before the ones it holds in depth-first order,
+    // so the held programs are weighed first in the reverse order
+    let mut cumulated_weights = weights.clone();
+    for &id in programs.dfs(root).iter().rev() {
+        let held: usize = programs
+            .neighbors(id)
+            .iter()
+            .map(|&child| cumulated_weights[child])
+            .sum();
+        cumulated_weights[id] += held;
+    }
+
+    Ok(Tower {
+        names,
+        weights,
+        programs,
+        root,
+        cumulated_weights,
+    })
+}
+
+/// Solver of the tower, working on its root program
+pub struct Day7;
+
+impl Day for Day7 {
+    type Input = Tower;
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        parse_tower(s)
+    }
+
+    fn part1(tower: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(Answer::from(tower.names.name(tower.root)))
+    }
+
+    fn part2(tower: &Self::Input) -> Result<Answer, SolveError> {
+        find_unbalanced(tower, tower.root)
+            .map(|(_, new_weight)| Answer::from(new_weight))
+            .ok_or_else(|| SolveError::NoSolution(String::from("No single culprit found")))
+    }
+}
+
+/// Returns the root of the program tower
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day7::one;
+/// let list = "\
+/// pbga (66)
+/// xhth (57)
+/// ebii (61)
+/// havc (66)
+/// ktlj (57)
+/// fwft (72) -> ktlj, cntj, xhth
+/// qoyq (66)
+/// padx (45) -> pbga, havc, qoyq
+/// tknk (41) -> ugml, padx, fwft
+/// jptl (61)
+/// ugml (68) -> gyxo, ebii, jptl
+/// gyxo (61)
+/// cntj (57)";
+/// assert_eq!("tknk", one(list).unwrap());
+/// ```
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day7::part1(&puzzle::read::<Day7>(s)?)
+}
+
+/// Recursively goes down the subtree and finds
+/// the program responsible for the unbalance
+fn find_unbalanced(tower: &Tower, program: usize) -> Option<(usize, usize)> {
+    let children = tower.programs.neighbors(program);
+    if children.is_empty() {
+        // No children
+        return None;
+    }
+
+    let mut children_weights = HashMap::new();
+    for &child in children {
+        let w = tower.cumulated_weights[child];
+        children_weights
+            .entry(w)
+            .or_insert_with(Vec::new)
+            .push(child);
+    }
+    if children_weights.len() <= 1 {
+        // All children have the same weight
+        return None;
+    }
+
+    let (current_weight, culprit) = match children_weights.iter().find(|&(_, v)| v.len() == 1) {
+        Some((w, v)) => (w, v[0]),
+        None => None?,
+    };
+    let (desired_weight, _) = children_weights.iter().find(|&(_, v)| v.len() > 1)?;
+    let new_weight = tower.weights[culprit] + desired_weight - current_weight;
+
+    find_unbalanced(tower, culprit).or(Some((culprit, new_weight)))
+}
+
+/// Calculates the new weight to give
+/// to the unbalanced program of the tower
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day7::two;
+/// let list = "\
+/// pbga (66)
+/// xhth (57)
+/// ebii (61)
+/// havc (66)
+/// ktlj (57)
+/// fwft (72) -> ktlj, cntj, xhth
+/// qoyq (66)
+/// padx (45) -> pbga, havc, qoyq
+/// tknk (41) -> ugml, padx, fwft
+/// jptl (61)
+/// ugml (68) -> gyxo, ebii, jptl
+/// gyxo (61)
+/// cntj (57)";
+/// assert_eq!("60", two(list).unwrap());
+/// ```
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day7::part2(&puzzle::read::<Day7>(s)?)
+}
+
+/// Program of a generated tower
+struct Generated {
+    name: String,
+    weight: i64,
+    children: Vec<usize>,
+}
+
+/// Generates a balanced tower of the provided total weight,
+/// every program holding either none or at least 3 other programs.
+/// Returns the index of the program at the bottom.
+fn generate_tower(
+    rng: &mut Rng,
+    names: &mut HashSet<String>,
+    programs: &mut Vec<Generated>,
+    total: i64,
+    depth: usize,
+) -> usize {
+    let name = loop {
+        let len = rng.range(4, 8) as usize;
+        let name = rng.word(len);
+        if names.insert(name.clone()) {
+            break name;
+        }
+    };
+    let n = rng.range(3, 5);
+    let (weight, children) = if depth > 0 && total > 4 * n {
+        let child_total = rng.range(total / (2 * n), (total - 1) / n);
+        let children = (0..n)
+            .map(|_| generate_tower(rng, names, programs, child_total, depth - 1))
+            .collect();
+        (total - n * child_total, children)
+    } else {
+        (total, vec![])
+    };
+    programs.push(Generated {
+        name,
+        weight,
+        children,
+    });
+    programs.len() - 1
+}
+
+/// Generates a random tower, where the weight of a single program is wrong.
+/// The scale is limited to 6, as the tower grows exponentially.
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let scale = scale.min(6);
+    let total = rng.range(1_000_000, 2_000_000) * 8i64.pow(scale as u32 - 1);
+    let mut programs = Vec::new();
+    generate_tower(rng, &mut HashSet::new(), &mut programs, total, 4 + scale);
+
+    // The last program is at the bottom, and has no siblings to be compared to
+    let wrong = rng.below(programs.len() - 1);
+    let delta = rng.range(1, 9);
+    if programs[wrong].weight > delta && rng.one_in(2) {
+        programs[wrong].weight -= delta;
+    } else {
+        programs[wrong].weight += delta;
+    }
+
+    let mut lines: Vec<String> = programs
+        .iter()
+        .map(|p| {
+            let children: Vec<&str> = p.children
+                .iter()
+                .map(|&c| programs[c].name.as_str())
+                .collect();
+            match children.len() {
+                0 => format!("{} ({})", p.name, p.weight),
+                _ => format!("{} ({}) -> {}", p.name, p.weight, children.join(", ")),
+            }
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+    lines.join("\n")
+}
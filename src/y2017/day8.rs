@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+use self::Op::*;
+use self::Cmp::*;
+use generate::Rng;
+use puzzle::{self, Day, Puzzle};
+use scan::{self, Scanner};
+use {Answer, SolveError};
+
+/// Day 8: I Heard You Like Registers
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 8,
+    title: "I Heard You Like Registers",
+    description: "Largest register value after running conditional instructions, then during the run",
+    input: "input/2017/day8",
+    parts: &[one, two],
+    params: &[],
+    generate,
+    checks: &[],
+    parse: puzzle::parse::<Day8>,
+    solve: puzzle::solve::<Day8>,
+};
+
+/// The arithmetic operator to apply to the target register
+enum Op {
+    Inc, // Increment
+    Dec, // Decrement
+}
+
+/// The comparison operator to use on the compared register
+enum Cmp {
+    EQ, // Equality
+    NE, // Inequality
+    LT, // Strictly lower
+    LE, // Lower or equal
+    GT, // Strictly greater
+    GE, // Greater or equal
+}
+
+/// The instruction to execute on the register bank
+pub struct Instruction {
+    target: String,
+    op: Op,
+    val: isize,
+    compared: String,
+    cmp: Cmp,
+    cond: isize,
+}
+
+/// Parses a complete block of instruction
+pub fn parse(s: &str) -> Result<Vec<Instruction>, SolveError> {
+    scan::lines(s, parse_instruction)
+}
+
+/// Parses a single instruction, checking its validity
+pub fn parse_instruction(l: &mut Scanner) -> Result<Instruction, SolveError> {
+    let target = String::from(l.name()?);
+
+    l.skip_whitespace();
+    let column = l.column();
+    let op = match l.name()? {
+        "inc" => Inc,
+        "dec" => Dec,
+        x => return Err(l.error_at(column, format!("Unknown operation {}", x))),
+    };
+
+    let val = l.number()?;
+    l.expect("if")?;
+    let compared = String::from(l.name()?);
+
+    l.skip_whitespace();
+    let column = l.column();
+    let cmp = match l.token("a comparator", |c| "=!<>".contains(c))? {
+        "==" => EQ,
+        "!=" => NE,
+        "<" => LT,
+        "<=" => LE,
+        ">" => GT,
+        ">=" => GE,
+        x => return Err(l.error_at(column, format!("Unknown comparator {}", x))),
+    };
+
+    let cond = l.number()?;
+
+    Ok(Instruction {
+        target,
+        op,
+        val,
+        compared,
+        cmp,
+        cond,
+    })
+}
+
+/// The register bank
+pub type Registers = HashMap<String, isize>;
+
+/// Evaluates the instruction on place
+/// on the provided mutable register bank
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day8::{Registers, parse, eval, max};
+/// let input = "\
+/// b inc 5 if a > 1
+/// a inc 1 if b < 5
+/// c dec -10 if a >= 1
+/// c inc -20 if c == 10";
+/// let v = parse(input).unwrap();
+/// let mut regs = Registers::new();
+/// assert_eq!(0, max(&regs));
+///
+/// eval(&v[0], &mut regs);
+/// assert_eq!(0, max(&regs));
+///
+/// eval(&v[1], &mut regs);
+/// assert_eq!(1, regs["a"]);
+///
+/// eval(&v[2], &mut regs);
+/// assert_eq!(10, regs["c"]);
+///
+/// eval(&v[3], &mut regs);
+/// assert_eq!(-10, regs["c"]);
+///
+/// assert_eq!(1, max(&regs));
+/// ```
+pub fn eval(i: &Instruction, regs: &mut Registers) {
+    let &compared = regs.get(&i.compared).unwrap_or(&0);
+    let cmp = match i.cmp {
+        EQ => compared == i.cond,
+        NE => compared != i.cond,
+        GT => compared > i.cond,
+        GE => compared >= i.cond,
+        LT => compared < i.cond,
+        LE => compared <= i.cond,
+    };
+    if cmp {
+        let &edited = regs.get(&i.target).unwrap_or(&0);
+        let edited = match i.op {
+            Inc => edited + i.val,
+            Dec => edited - i.val,
+        };
+        regs.insert(i.target.to_owned(), edited);
+    }
+}
+
+/// Evaluates all the provided instructions on a blank register bank,
+/// and returns the resulting register bank
+pub fn eval_all(v: &[Instruction]) -> Registers {
+    let mut regs = Registers::new();
+    for i in v {
+        eval(i, &mut regs);
+    }
+    regs
+}
+
+/// Calculates the maximal value currently stored in the bank
+pub fn max(regs: &Registers) -> isize {
+    *regs.values().max().unwrap_or(&0)
+}
+
+/// Solver of the registers, working on the parsed instructions
+pub struct Day8;
+
+impl Day for Day8 {
+    type Input = Vec<Instruction>;
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        parse(s)
+    }
+
+    fn part1(v: &Self::Input) -> Result<Answer, SolveError> {
+        let regs = eval_all(v);
+        Ok(max(&regs).into())
+    }
+
+    fn part2(v: &Self::Input) -> Result<Answer, SolveError> {
+        let mut regs = Registers::new();
+        let mut m = max(&regs);
+
+        for i in v {
+            eval(i, &mut regs);
+            let new_m = max(&regs);
+            if new_m > m {
+                m = new_m;
+            }
+        }
+
+        Ok(m.into())
+    }
+}
+
+/// Calculates the maximum value stored in the register bank
+/// at the end of the program
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day8::part1(&puzzle::read::<Day8>(s)?)
+}
+
+/// Calculates the maximum value ever stored in the bank
+/// during the whole execution of the program
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day8::part2(&puzzle::read::<Day8>(s)?)
+}
+
+/// Generates random instructions on a few registers
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let registers: Vec<String> = (0..25)
+        .map(|_| {
+            let len = rng.range(1, 3) as usize;
+            rng.word(len)
+        })
+        .collect();
+    let instructions: Vec<String> = (0..1000 * scale)
+        .map(|_| {
+            format!(
+                "{} {} {} if {} {} {}",
+                rng.choose(&registers),
+                rng.choose(&["inc", "dec"]),
+                rng.range(-1000, 1000),
+                rng.choose(&registers),
+                rng.choose(&[">", "<", ">=", "<=", "==", "!="]),
+                rng.range(-10, 10)
+            )
+        })
+        .collect();
+    instructions.join("\n")
+}
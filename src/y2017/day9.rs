@@ -0,0 +1,217 @@
+use std::str::Chars;
+use generate::Rng;
+use puzzle::{self, Day, Puzzle};
+use scan::{self, Scanner};
+use {Answer, SolveError};
+
+/// Day 9: Stream Processing
+pub const PUZZLE: Puzzle = Puzzle {
+    year: 2017,
+    day: 9,
+    title: "Stream Processing",
+    description: "Score the nested groups of a stream, then count the characters of its garbage",
+    input: "input/2017/day9",
+    parts: &[one, two],
+    params: &[],
+    generate,
+    checks: &[],
+    parse: puzzle::parse::<Day9>,
+    solve: puzzle::solve::<Day9>,
+};
+
+/// Parses the content of a well-formed group,
+/// and returns the corresponding group score
+fn parse_group_score(stream: &mut Chars, level: usize) -> usize {
+    let mut score = level;
+    while let Some(c) = stream.next() {
+        match c {
+            '{' => {
+                score += parse_group_score(stream, level + 1);
+            }
+            '<' => {
+                score_garbage(stream);
+            }
+            '}' => {
+                return score;
+            }
+            _ => {} // A comma, continue to the next sub-group
+        }
+    }
+    score
+}
+
+/// Parses the content of a garbage block,
+/// and returns the number of unescaped characters
+fn parse_group_garbage(stream: &mut Chars) -> usize {
+    let mut score = 0;
+    while let Some(c) = stream.next() {
+        match c {
+            '{' => {
+                score += parse_group_garbage(stream);
+            }
+            '<' => {
+                score += score_garbage(stream);
+            }
+            '}' => {
+                return score;
+            }
+            _ => {} // A comma, continue to the next sub-group
+        }
+    }
+    score
+}
+
+/// Parses the content of a well-formed croup,
+/// and returns the number of unescaped characters in its garbage
+fn score_garbage(stream: &mut Chars) -> usize {
+    let mut score = 0;
+    while let Some(c) = stream.next() {
+        match c {
+            '>' => {
+                return score;
+            }
+            '!' => {
+                stream.next();
+            }
+            _ => {
+                score += 1;
+            }
+        }
+    }
+
+    score
+}
+
+/// Reads the stream, checking that its groups and garbage are closed
+/// and that the groups only hold groups, garbage and commas
+fn stream(l: &mut Scanner) -> Result<String, SolveError> {
+    l.skip_whitespace();
+    let start = l.column();
+    let mut groups = Vec::new();
+    let mut garbage = None;
+    let mut chars = l.rest().trim_end().chars().enumerate();
+    while let Some((i, c)) = chars.next() {
+        let column = start + i;
+        match (garbage, c) {
+            (Some(_), '!') => {
+                chars.next();
+            }
+            (Some(_), '>') => garbage = None,
+            (Some(_), _) => {}
+            (None, '{') => groups.push(column),
+            (None, '}') if groups.pop().is_none() => {
+                return Err(l.error_at(column, "Unmatched '}'"));
+            }
+            (None, '}') | (None, ',') => {}
+            (None, '<') => garbage = Some(column),
+            (None, _) => {
+                return Err(l.error_at(column, format!("Unexpected character {:?} in a group", c)));
+            }
+        }
+    }
+    if let Some(column) = garbage {
+        return Err(l.error_at(column, "Unclosed garbage"));
+    }
+    if let Some(&column) = groups.last() {
+        return Err(l.error_at(column, "Unclosed group"));
+    }
+    Ok(String::from(l.take_while(|_| true).trim_end()))
+}
+
+/// Solver of the stream, working on its trimmed content
+pub struct Day9;
+
+impl Day for Day9 {
+    type Input = String;
+
+    fn parse(s: &str) -> Result<Self::Input, SolveError> {
+        scan::line(s, stream)
+    }
+
+    fn part1(s: &Self::Input) -> Result<Answer, SolveError> {
+        let mut stream = s.chars();
+        Ok(parse_group_score(&mut stream, 0).into())
+    }
+
+    fn part2(s: &Self::Input) -> Result<Answer, SolveError> {
+        let mut stream = s.chars();
+        Ok(parse_group_garbage(&mut stream).into())
+    }
+}
+
+/// Calculates the score of a stream block
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day9::one;
+/// assert_eq!("1", one("{}").unwrap());
+/// assert_eq!("6", one("{{{}}}").unwrap());
+/// assert_eq!("5", one("{{},{}}").unwrap());
+/// assert_eq!("16", one("{{{},{},{{}}}}").unwrap());
+/// assert_eq!("1", one("{<a>,<a>,<a>,<a>}").unwrap());
+/// assert_eq!("9", one("{{<ab>},{<ab>},{<ab>},{<ab>}}").unwrap());
+/// assert_eq!("9", one("{{<!!>},{<!!>},{<!!>},{<!!>}}").unwrap());
+/// assert_eq!("3", one("{{<a!>},{<a!>},{<a!>},{<ab>}}").unwrap());
+/// ```
+pub fn one(s: &str) -> Result<Answer, SolveError> {
+    Day9::part1(&puzzle::read::<Day9>(s)?)
+}
+
+/// Calculates the number of characters
+/// in the garbage of a stream block
+///
+/// # Examples
+/// ```
+/// use advent_of_code::y2017::day9::two;
+/// assert_eq!("0", two("<>").unwrap());
+/// assert_eq!("17", two("<random characters>").unwrap());
+/// assert_eq!("3", two("<<<<>").unwrap());
+/// assert_eq!("2", two("<{!>}>").unwrap());
+/// assert_eq!("0", two("<!!>").unwrap());
+/// assert_eq!("0", two("<!!!>>").unwrap());
+/// assert_eq!("10", two("<{o\"i!a,<{i<a>").unwrap());
+/// ```
+pub fn two(s: &str) -> Result<Answer, SolveError> {
+    Day9::part2(&puzzle::read::<Day9>(s)?)
+}
+
+/// Generates random garbage, with cancelled characters
+fn generate_garbage(rng: &mut Rng, budget: &mut usize) -> String {
+    let mut garbage = String::from("<");
+    for _ in 0..rng.range(0, 20) {
+        if rng.one_in(6) {
+            garbage.push('!');
+            garbage.push(*rng.choose(&['!', '>', '<', '{', 'a']));
+        } else {
+            garbage.push(*rng.choose(&['a', 'e', 'i', 'o', 'u', '{', '}', '<', '\'', '"', ',']));
+        }
+    }
+    garbage.push('>');
+    *budget = budget.saturating_sub(garbage.len());
+    garbage
+}
+
+/// Generates a random group, holding nested groups and garbage,
+/// as long as the budget of characters has not been spent
+fn generate_group(rng: &mut Rng, budget: &mut usize, depth: usize) -> String {
+    *budget = budget.saturating_sub(2);
+    let mut items = Vec::new();
+    while *budget > 0 && !rng.one_in(4) {
+        if depth < 10 && rng.one_in(2) {
+            items.push(generate_group(rng, budget, depth + 1));
+        } else {
+            items.push(generate_garbage(rng, budget));
+        }
+    }
+    format!("{{{}}}", items.join(","))
+}
+
+/// Generates a random stream, made of a single outermost group
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut budget = 20_000 * scale;
+    let mut groups = Vec::new();
+    while budget > 0 {
+        groups.push(generate_group(rng, &mut budget, 1));
+    }
+    format!("{{{}}}", groups.join(","))
+}
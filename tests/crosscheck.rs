@@ -0,0 +1,37 @@
+//! Cross-checks the optimised solvers with their reference implementations
+//! on a few generated inputs, and on the examples of the puzzle texts.
+
+extern crate advent_of_code;
+
+use advent_of_code::crosscheck::cross_check;
+use advent_of_code::{get_puzzle, PUZZLES};
+use std::fs;
+
+/// Fails with every mismatch found
+fn assert_agree(mismatches: Vec<String>) {
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n\n"));
+}
+
+#[test]
+fn generated() {
+    let mismatches = PUZZLES
+        .iter()
+        .flat_map(|puzzle| cross_check(puzzle, 0..5, None))
+        .map(|m| m.to_string())
+        .collect();
+    assert_agree(mismatches);
+}
+
+#[test]
+fn examples() {
+    let examples = [(16, "tests/data/2017/day16/dance"), (17, "tests/data/2017/day17/spinlock")];
+    let mismatches = examples
+        .iter()
+        .flat_map(|&(day, path)| {
+            let input = fs::read_to_string(path).expect("Could not read the example");
+            cross_check(get_puzzle(2017, day).unwrap(), 0..1, Some(input.trim()))
+        })
+        .map(|m| m.to_string())
+        .collect();
+    assert_agree(mismatches);
+}
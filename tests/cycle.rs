@@ -0,0 +1,31 @@
+//! Finds the same cycles with every strategy of the cycle detection.
+
+extern crate advent_of_code;
+
+use advent_of_code::cycle::{detect, Cycle, Strategy};
+use advent_of_code::y2017::day6::MemoryBank;
+
+const STRATEGIES: [Strategy; 3] = [Strategy::Hash, Strategy::Floyd, Strategy::Brent];
+
+#[test]
+fn memory_banks() {
+    let bank = MemoryBank::parse("0 2 7 0").unwrap();
+    for &strategy in &STRATEGIES {
+        let cycle = detect(bank.clone(), MemoryBank::redistribute, strategy);
+        assert_eq!(Cycle { tail: 1, length: 4 }, cycle, "{:?}", strategy);
+    }
+}
+
+#[test]
+fn tails_and_lengths() {
+    for tail in 0..20 {
+        for length in 1..20 {
+            let step = |&x: &usize| if x + 1 < tail + length { x + 1 } else { tail };
+            for &strategy in &STRATEGIES {
+                let cycle = detect(0, step, strategy);
+                assert_eq!(Cycle { tail, length }, cycle, "{:?}", strategy);
+                assert_eq!((tail..tail + length).collect::<Vec<_>>(), cycle.states(0, step));
+            }
+        }
+    }
+}
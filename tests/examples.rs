@@ -0,0 +1,145 @@
+//! Runs the examples of the puzzle texts stored in `tests/data`.
+//!
+//! Each `(year)/dayN` directory holds example inputs, and next to each input
+//! a `.expected` file listing its answers, one `part answer [name=value]...` line
+//! per part, with the values of the parameters the example is solved with.
+//! Empty lines and lines starting with `#` are ignored.
+
+extern crate advent_of_code;
+
+use advent_of_code::param::{self, parse_assignment, Values};
+use advent_of_code::{get_puzzle, get_solver, Input};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directory holding the examples
+const DATA: &str = "tests/data";
+
+/// An answer expected from a part on an example input
+struct Case {
+    year: u32,
+    day: u32,
+    part: u32,
+    input: PathBuf,
+    answer: String,
+    params: Values,
+}
+
+/// Parses a line of a `.expected` file
+fn parse_case(year: u32, day: u32, input: &Path, line: &str) -> Result<Case, String> {
+    let mut tokens = line.split_whitespace();
+    let part = tokens
+        .next()
+        .ok_or("Missing part")?
+        .parse()
+        .map_err(|e| format!("Could not parse part as int: {}", e))?;
+    let answer = tokens.next().ok_or("Missing answer")?.to_string();
+    let params = tokens.map(parse_assignment).collect::<Result<_, _>>()?;
+    Ok(Case {
+        year,
+        day,
+        part,
+        input: input.to_path_buf(),
+        answer,
+        params,
+    })
+}
+
+/// Subdirectories whose names are numbers once the prefix is removed,
+/// ordered by number
+fn numbered(dir: &Path, prefix: &str) -> Vec<(u32, PathBuf)> {
+    let mut dirs: Vec<(u32, PathBuf)> = fs::read_dir(dir)
+        .expect("Could not read an examples directory")
+        .filter_map(|entry| {
+            let path = entry.expect("Could not read an examples directory").path();
+            let name = path.file_name()?.to_str()?.to_string();
+            let n = name.strip_prefix(prefix)?.parse().ok()?;
+            Some((n, path))
+        })
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+/// Finds the cases of every day, ordered by year, day and input name
+fn discover() -> Vec<Case> {
+    let days: Vec<(u32, u32, PathBuf)> = numbered(Path::new(DATA), "")
+        .into_iter()
+        .flat_map(|(year, dir)| {
+            numbered(&dir, "day")
+                .into_iter()
+                .map(move |(day, dir)| (year, day, dir))
+        })
+        .collect();
+
+    let mut cases = Vec::new();
+    for (year, day, dir) in days {
+        let mut expectations: Vec<PathBuf> = fs::read_dir(&dir)
+            .expect("Could not read an examples directory")
+            .map(|entry| entry.expect("Could not read an example").path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "expected"))
+            .collect();
+        expectations.sort();
+
+        for expected in expectations {
+            let input = expected.with_extension("");
+            let content = fs::read_to_string(&expected).expect("Could not read the answers");
+            for (i, line) in content.lines().enumerate() {
+                if line.trim().is_empty() || line.trim().starts_with('#') {
+                    continue;
+                }
+                let case = parse_case(year, day, &input, line)
+                    .unwrap_or_else(|e| panic!("{}:{}: {}", expected.display(), i + 1, e));
+                cases.push(case);
+            }
+        }
+    }
+    cases
+}
+
+/// Solves the case, describing why it failed if it did
+fn check(case: &Case) -> Result<(), String> {
+    let puzzle = get_puzzle(case.year, case.day).ok_or("no puzzle for this day")?;
+    let solver = get_solver(case.year, case.day, case.part).ok_or("no solver for this part")?;
+    param::check(puzzle.params, &case.params)?;
+    let raw = fs::read_to_string(&case.input).map_err(|e| e.to_string())?;
+
+    param::set_current(case.params.clone());
+    let answer = solver(&Input::normalize(&raw));
+    param::set_current(Values::new());
+
+    match answer {
+        Ok(ref answer) if *answer == case.answer.as_str() => Ok(()),
+        Ok(answer) => Err(format!("got {}, expected {}", answer, case.answer)),
+        Err(e) => Err(format!("failed: {}", e)),
+    }
+}
+
+#[test]
+fn examples() {
+    let cases = discover();
+    assert!(!cases.is_empty(), "No example found in {}", DATA);
+
+    let failures: Vec<String> = cases
+        .iter()
+        .filter_map(|case| {
+            check(case).err().map(|e| {
+                format!(
+                    "{} day {} part {} on {}: {}",
+                    case.year,
+                    case.day,
+                    case.part,
+                    case.input.display(),
+                    e
+                )
+            })
+        })
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "{} of {} examples failed:\n{}",
+        failures.len(),
+        cases.len(),
+        failures.join("\n")
+    );
+}
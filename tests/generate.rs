@@ -0,0 +1,62 @@
+//! Checks the random inputs generated for every day:
+//! they are the same for a given seed, and they can be parsed.
+//! The days whose generators guarantee a solution are also solved.
+
+extern crate advent_of_code;
+
+use advent_of_code::generate::generate;
+use advent_of_code::{get_puzzle, get_solver, PUZZLES};
+
+/// Seeds every generator is checked with
+const SEEDS: [u64; 3] = [0, 1, 2017];
+
+#[test]
+fn deterministic() {
+    for puzzle in PUZZLES {
+        for &seed in &SEEDS {
+            assert_eq!(
+                generate(puzzle, seed, 1),
+                generate(puzzle, seed, 1),
+                "{} day {} with seed {}",
+                puzzle.year,
+                puzzle.day,
+                seed
+            );
+        }
+        assert_ne!(
+            generate(puzzle, 0, 2),
+            generate(puzzle, 1, 2),
+            "{} day {}",
+            puzzle.year,
+            puzzle.day
+        );
+    }
+}
+
+#[test]
+fn parsed() {
+    for puzzle in PUZZLES {
+        for &seed in &SEEDS {
+            let input = generate(puzzle, seed, 1);
+            if let Err(e) = (puzzle.parse)(&input) {
+                panic!("{} day {} with seed {}: {}", puzzle.year, puzzle.day, seed, e);
+            }
+        }
+    }
+}
+
+#[test]
+fn solved() {
+    for &day in &[7, 12, 18, 19, 21] {
+        let puzzle = get_puzzle(2017, day).unwrap();
+        for &seed in &SEEDS {
+            let input = generate(puzzle, seed, 1);
+            for part in 1..3 {
+                let solver = get_solver(2017, day, part).unwrap();
+                if let Err(e) = solver(&input) {
+                    panic!("day {} part {} with seed {}: {}", day, part, seed, e);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Parses inputs holding a malformed line,
+//! which must be reported with its position instead of being skipped.
+
+extern crate advent_of_code;
+
+use advent_of_code::{get_puzzle, SolveError};
+
+/// A malformed input of a day of 2017, with the position of the error
+const CASES: &[(u32, &str, &str)] = &[
+    (1, "1221x3", "line 1, column 5"),
+    (2, "5 1 9\n7 x 3", "line 2, column 3"),
+    (3, "12x", "line 1, column 3"),
+    (4, "aa bb\ncc d-d", "line 2, column 5"),
+    (5, "0\n3\nz", "line 3, column 1"),
+    (6, "0 2 7 O", "line 1, column 7"),
+    (7, "pbga (66)\nfwft 72 -> ktlj", "line 2, column 6"),
+    (8, "b inc 5 if a > 1\na inc 1 if b =< 5", "line 2, column 14"),
+    (9, "{{<a>},{x}}", "line 1, column 9"),
+    (9, "{{<!>},{}}", "line 1, column 3"),
+    (11, "ne,ne,nn", "line 1, column 7"),
+    (12, "0 <-> 2\n1 <-> 1\n2 <-> 0 3", "line 3, column 9"),
+    (13, "0: 3\n1 2", "line 2, column 3"),
+    (14, "flq rgnkx", "line 1, column 5"),
+    (15, "Generator A starts with 65\nGenerator B begins with 8921", "line 2, column 13"),
+    (16, "s1,x3/4,q1", "line 1, column 9"),
+    (17, "3 4", "line 1, column 3"),
+    (18, "set a 1\nadd a two", "line 2, column 7"),
+    (19, "  |  \n  *  ", "line 2, column 3"),
+    (20, "p=<3,0,0>, v=<2,0,0>, a=<-1,0,0>\np=<4,0,0>, v=<0,0>, a=<-2,0,0>", "line 2, column 18"),
+    (21, "../.# => ##./#../...\n.#./..#/### => #..#/..../..x./#..#", "line 2, column 16"),
+    (21, "../.# => ##./#../...\n.#./..#/### => #..../...../...../...../.....", "line 2, column 16"),
+    (21, "##/## => ###/###/###\n../.. => ##/##", "line 2, column 10"),
+    (21, "../.# => ##./#../...\n.#./..#/### => ###/###/###", "line 2, column 16"),
+    (21, "#/# => ##/##", "line 1, column 1"),
+    (22, "..#\n#.x\n...", "line 2, column 3"),
+    (22, "..#\n#.\n...", "line 2, column 3"),
+    (23, "set b 1\njnz 1 ab", "line 2, column 7"),
+    (24, "0/2\n2/2\n2-3", "line 3, column 2"),
+    (25, "Begin in state A.\nPerform a diagnostic checksum after x steps.", "line 2, column 37"),
+    (25, "Begin in state A.\n\nIn state A:\n  If the current value is 0:\n    - Move one slot to the up.", "line 5, column 28"),
+    (25, "Begin in state A.\n  - Write the value 1.", "line 2, column 5"),
+];
+
+#[test]
+fn reported() {
+    for &(day, input, position) in CASES {
+        let puzzle = get_puzzle(2017, day).unwrap();
+        match (puzzle.parse)(input) {
+            Ok(_) => panic!("Day {} accepted the malformed input {:?}", day, input),
+            Err(e) => assert!(
+                e.to_string().starts_with(position),
+                "Day {} reported {:?} instead of an error at {}",
+                day,
+                e.to_string(),
+                position
+            ),
+        }
+    }
+}
+
+/// Rejects the inputs made only of whitespace, except for the knot hash
+/// of day 10, which hashes any text including an empty one
+#[test]
+fn empty() {
+    for day in 1..26 {
+        let puzzle = get_puzzle(2017, day).unwrap();
+        for part in puzzle.part_numbers() {
+            if (day, part) == (10, 2) {
+                continue;
+            }
+            for input in &["", "\n \n"] {
+                match puzzle.solver(part).unwrap()(input) {
+                    Err(SolveError::InvalidInput(_)) => {}
+                    Err(e) => panic!("Day {} reported {} for an empty input", day, e),
+                    Ok(answer) => panic!("Day {} answered {} for an empty input", day, answer),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn knot_lengths() {
+    let e = advent_of_code::y2017::day10::one("3,4,,1").unwrap_err();
+    assert!(e.to_string().starts_with("line 1, column 5"), "{}", e);
+}